@@ -11,7 +11,7 @@ use std::any::Any;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(any(feature = "glow", feature = "wgpu"))]
-pub use crate::native::winit_integration::UserEvent;
+pub use crate::native::winit_integration::{UserEvent, UserEventSender};
 
 #[cfg(not(target_arch = "wasm32"))]
 use raw_window_handle::{
@@ -41,7 +41,18 @@ pub type EventLoopBuilderHook = Box<dyn FnOnce(&mut EventLoopBuilder)>;
 #[cfg(any(feature = "glow", feature = "wgpu"))]
 pub type WindowBuilderHook = Box<dyn FnOnce(egui::ViewportBuilder) -> egui::ViewportBuilder>;
 
-type DynError = Box<dyn std::error::Error + Send + Sync>;
+/// Hook into the building of every native window, including those of child viewports.
+///
+/// Called with the id of the viewport and the [`egui::ViewportBuilder`] requested for it,
+/// and returns the builder to actually use.
+/// It is called again whenever the viewport is updated, so it should be a pure function of its input.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+pub type ViewportBuilderHook = std::sync::Arc<
+    dyn Fn(egui::ViewportId, egui::ViewportBuilder) -> egui::ViewportBuilder + Send + Sync,
+>;
+
+pub(crate) type DynError = Box<dyn std::error::Error + Send + Sync>;
 
 /// This is how your app is created.
 ///
@@ -49,6 +60,23 @@ type DynError = Box<dyn std::error::Error + Send + Sync>;
 pub type AppCreator<'app> =
     Box<dyn 'app + FnOnce(&CreationContext<'_>) -> Result<Box<dyn 'app + App>, DynError>>;
 
+/// A future that creates your app, for when that requires async work
+/// such as fetching a config file or an auth token.
+///
+/// See [`crate::run_native_async`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type AppFuture = std::pin::Pin<
+    Box<dyn std::future::Future<Output = Result<Box<dyn App + Send>, DynError>> + Send>,
+>;
+
+/// A future that creates your app, for when that requires async work
+/// such as fetching a config file or an auth token.
+///
+/// See [`crate::WebRunner::start_async`].
+#[cfg(target_arch = "wasm32")]
+pub type AppFuture =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Result<Box<dyn App>, DynError>>>>;
+
 /// Data that is passed to [`AppCreator`] that can be used to setup and initialize your app.
 pub struct CreationContext<'s> {
     /// The egui Context.
@@ -63,6 +91,13 @@ pub struct CreationContext<'s> {
     /// You can use the storage to restore app state(requires the "persistence" feature).
     pub storage: Option<&'s dyn Storage>,
 
+    /// The rendering backend that was actually chosen to run the app.
+    ///
+    /// Use this to pick between e.g. a `glow` or a `wgpu` [`egui::PaintCallback`]
+    /// when both features are enabled.
+    #[cfg(any(feature = "glow", feature = "wgpu"))]
+    pub renderer: Renderer,
+
     /// The [`glow::Context`] allows you to initialize OpenGL resources (e.g. shaders) that
     /// you might want to use later from a [`egui::PaintCallback`].
     ///
@@ -109,14 +144,16 @@ impl HasDisplayHandle for CreationContext<'_> {
     }
 }
 
-impl CreationContext<'_> {
-    /// Create a new empty [CreationContext] for testing [App]s in kittest.
-    #[doc(hidden)]
-    pub fn _new_kittest(egui_ctx: egui::Context) -> Self {
+impl<'s> CreationContext<'s> {
+    /// A [`CreationContext`] without storage, graphics context or window,
+    /// for creating an [`App`] in tests, e.g. with [`Self::with_storage`].
+    pub fn new_for_tests(egui_ctx: egui::Context) -> Self {
         Self {
             egui_ctx,
             integration_info: IntegrationInfo::mock(),
             storage: None,
+            #[cfg(any(feature = "glow", feature = "wgpu"))]
+            renderer: Renderer::default(),
             #[cfg(feature = "glow")]
             gl: None,
             #[cfg(feature = "glow")]
@@ -129,6 +166,30 @@ impl CreationContext<'_> {
             raw_display_handle: Err(HandleError::NotSupported),
         }
     }
+
+    /// Create a new empty [CreationContext] for testing [App]s in kittest.
+    #[doc(hidden)]
+    pub fn _new_kittest(egui_ctx: egui::Context) -> Self {
+        Self::new_for_tests(egui_ctx)
+    }
+
+    /// Use the given [`Storage`], e.g. a [`crate::storage::MemoryStorage`].
+    ///
+    /// Useful for testing how an [`App`] restores its state,
+    /// without going through [`crate::run_native`]:
+    ///
+    /// ```
+    /// use eframe::{CreationContext, storage::MemoryStorage};
+    ///
+    /// let storage = MemoryStorage::from_iter([("app".to_owned(), "42".to_owned())]);
+    /// let cc = CreationContext::new_for_tests(eframe::egui::Context::default()).with_storage(&storage);
+    /// assert!(cc.storage.is_some());
+    /// ```
+    #[inline]
+    pub fn with_storage(mut self, storage: &'s dyn Storage) -> Self {
+        self.storage = Some(storage);
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -168,6 +229,20 @@ pub trait App {
         None
     }
 
+    /// Like [`Self::as_any_mut`], but for read-only access with [`crate::WebRunner::app_ref`].
+    ///
+    /// Just copy-paste this as your implementation:
+    /// ```ignore
+    /// #[cfg(target_arch = "wasm32")]
+    /// fn as_any(&self) -> Option<&dyn std::any::Any> {
+    ///     Some(&*self)
+    /// }
+    /// ```
+    #[cfg(target_arch = "wasm32")]
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
     /// Called on shutdown, and perhaps at regular intervals. Allows you to save state.
     ///
     /// Only called when the "persistence" feature is enabled.
@@ -178,9 +253,116 @@ pub trait App {
     /// The path can be customized via [`NativeOptions::persistence_path`].
     fn save(&mut self, _storage: &mut dyn Storage) {}
 
+    /// Called once per frame, after [`Self::update`], with the files that were
+    /// dropped onto the window since the previous frame.
+    ///
+    /// On web only the file name is known, so each path is just the name of the dropped file.
+    /// The file contents are available in [`egui::RawInput::dropped_files`] as usual.
+    fn on_drop_files(&mut self, _ctx: &egui::Context, _paths: &[std::path::PathBuf]) {}
+
+    /// Called when the root window gains or loses keyboard focus.
+    ///
+    /// This is called before egui itself reacts to the focus change,
+    /// so it is a good place to pause audio, mark the user as away, etc.
+    ///
+    /// On web this follows the focus of the canvas.
+    fn on_focus_changed(&mut self, _ctx: &egui::Context, _focused: bool) {}
+
+    /// Called when the OS switches between dark and light mode.
+    ///
+    /// With [`NativeOptions::follow_system_theme`] this is called after eframe has switched
+    /// to the default [`egui::Visuals`] of the new theme,
+    /// so you can override specific colors here.
+    ///
+    /// Only called on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn on_theme_changed(&mut self, _ctx: &egui::Context, _dark: bool) {}
+
+    /// Called with the image captured after [`Frame::request_screenshot`].
+    ///
+    /// Only called on native.
+    fn on_screenshot(&mut self, _image: egui::ColorImage) {}
+
+    /// Called when an item of the [`NativeOptions::tray_menu`] is clicked,
+    /// with the `id` of that [`TrayMenuItem::Item`].
+    #[cfg(all(feature = "tray_icon", not(target_arch = "wasm32")))]
+    fn on_tray_event(&mut self, _ctx: &egui::Context, _id: u32) {}
+
+    /// Called with each event sent with [`UserEventSender::send`],
+    /// e.g. results of background work or IPC messages.
+    ///
+    /// Use [`Any::downcast_ref`] to recover the type you sent.
+    /// A repaint is requested after this, so [`Self::update`] can show the result.
+    ///
+    /// Get a [`UserEventSender`] from [`Frame::event_loop_proxy`]. Only called on native.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn on_user_event(&mut self, _ctx: &egui::Context, _event: &dyn Any) {}
+
+    /// Called when the app is suspended, e.g. when it is sent to the background on Android.
+    ///
+    /// On Android the window surface is destroyed right after this call,
+    /// so this is the place to release GPU resources (e.g. those used by
+    /// [`egui::PaintCallback`]s) that depend on it.
+    ///
+    /// Only called on native.
+    fn on_suspend(&mut self, _ctx: &egui::Context) {}
+
+    /// Called when the app is resumed after having been suspended,
+    /// once the window surface has been recreated.
+    ///
+    /// This is not called for the very first resume that creates the app.
+    ///
+    /// Only called on native.
+    fn on_resume(&mut self, _ctx: &egui::Context) {}
+
+    /// Called when the user asks to close the root window, e.g. by clicking the close button,
+    /// before eframe decides whether to close.
+    ///
+    /// This is a good place to flush pending work or ask "save unsaved changes?".
+    ///
+    /// Return [`ExitAction::Cancel`] to keep the window open, e.g. to show a
+    /// "you have unsaved changes" modal, without having to send
+    /// [`egui::ViewportCommand::CancelClose`] yourself.
+    ///
+    /// This is not called when the app itself closes the window with [`egui::ViewportCommand::Close`].
+    ///
+    /// Only called on native.
+    fn on_exit_requested(&mut self, _ctx: &egui::Context) -> ExitAction {
+        ExitAction::Proceed
+    }
+
+    /// The version of the format your app persists its state in.
+    ///
+    /// Bump this when you make a breaking change to what you store,
+    /// and handle the upgrade in [`Self::migrate_storage`].
+    fn storage_version(&self) -> u32 {
+        0
+    }
+
+    /// Upgrade persisted state written by an older [`Self::storage_version`].
+    ///
+    /// Called once, after the app has been created and before the first call to [`Self::update`],
+    /// if the stored version is lower than [`Self::storage_version`].
+    /// `from_version` is `0` if no version was stored, e.g. on the very first run.
+    ///
+    /// Afterwards the new version is written to storage.
+    /// Returning without writing anything is safe.
+    fn migrate_storage(&mut self, _storage: &mut dyn Storage, _from_version: u32) {}
+
+    /// Some state that eframe persists could not be restored, e.g. because its format changed.
+    ///
+    /// eframe then starts with the default for that state, e.g. the default window position.
+    /// Called once, right after the app has been created (and before [`Self::migrate_storage`]),
+    /// for each key of eframe's own state (the window settings and the egui memory)
+    /// that failed to load.
+    ///
+    /// Your own state is loaded by you, so use [`crate::try_get_value`] to see why that failed.
+    fn on_storage_load_error(&mut self, _key: &str, _err: &dyn std::error::Error) {}
+
     /// Called once on shutdown, after [`Self::save`].
     ///
-    /// If you need to abort an exit check `ctx.input(|i| i.viewport().close_requested())`
+    /// If you need to abort an exit use [`Self::on_exit_requested`],
+    /// or check `ctx.input(|i| i.viewport().close_requested())`
     /// and respond with [`egui::ViewportCommand::CancelClose`].
     ///
     /// To get a [`glow`] context you need to compile with the `glow` feature flag,
@@ -190,7 +372,7 @@ pub trait App {
 
     /// Called once on shutdown, after [`Self::save`].
     ///
-    /// If you need to abort an exit use [`Self::on_close_event`].
+    /// If you need to abort an exit use [`Self::on_exit_requested`].
     #[cfg(not(feature = "glow"))]
     fn on_exit(&mut self) {}
 
@@ -198,6 +380,12 @@ pub trait App {
     // Settings:
 
     /// Time between automatic calls to [`Self::save`]
+    ///
+    /// Return [`std::time::Duration::ZERO`] to turn off auto-saving,
+    /// e.g. if you only save on explicit user action.
+    /// [`Self::save`] is still called when the app shuts down.
+    ///
+    /// Can be overridden at runtime with [`Frame::set_auto_save_interval`].
     fn auto_save_interval(&self) -> std::time::Duration {
         std::time::Duration::from_secs(30)
     }
@@ -227,6 +415,27 @@ pub trait App {
         true
     }
 
+    /// Controls which parts of the egui memory are persisted
+    /// (only if the "persistence" feature is enabled).
+    ///
+    /// By default this is everything if [`Self::persist_egui_memory`] returns `true`,
+    /// and nothing otherwise.
+    fn persist_egui_memory_flags(&self) -> MemoryPersistFlags {
+        if self.persist_egui_memory() {
+            MemoryPersistFlags::all()
+        } else {
+            MemoryPersistFlags::empty()
+        }
+    }
+
+    /// Called once per frame, before egui starts building the UI for it.
+    ///
+    /// Unlike [`Self::update`], no UI is being built yet, so this is a good place to
+    /// advance simulation state or issue GPU commands without worrying about partially built widgets.
+    ///
+    /// It is called before [`Self::raw_input_hook`].
+    fn pre_frame(&mut self, _ctx: &egui::Context, _frame: &mut Frame) {}
+
     /// A hook for manipulating or filtering raw input before it is processed by [`Self::update`].
     ///
     /// This function provides a way to modify or filter input events before they are processed by egui.
@@ -244,6 +453,98 @@ pub trait App {
     ///
     /// This function does not return a value. Any changes to the input should be made directly to `_raw_input`.
     fn raw_input_hook(&mut self, _ctx: &egui::Context, _raw_input: &mut egui::RawInput) {}
+
+    /// Called exactly once, right after the first frame has been painted and the window is shown.
+    ///
+    /// This is the first moment the user can see your content, so it is a good place to
+    /// dismiss a splash screen, start timers, or record startup analytics.
+    ///
+    /// Only called on native.
+    fn on_first_frame(&mut self, _ctx: &egui::Context, _frame: &mut Frame) {}
+
+    /// Called after the root viewport has been painted for this frame.
+    ///
+    /// `window_size_px` is the size of the region that was rendered, in physical pixels.
+    ///
+    /// This can be used for post-processing or exporting the rendered frame,
+    /// e.g. using [`Frame::wgpu_render_state`] or [`Frame::gl`].
+    fn post_rendering(&mut self, _window_size_px: [u32; 2], _frame: &mut Frame) {}
+
+    /// A hook for inspecting or modifying the output of each frame, before eframe acts on it.
+    ///
+    /// This is the counterpart of [`Self::raw_input_hook`], and is called right after [`Self::update`].
+    ///
+    /// It can be used to e.g. log or redirect [`egui::PlatformOutput::commands`] (such as copied text),
+    /// or to strip viewport commands that your app does not want eframe to act on.
+    fn raw_output_hook(&mut self, _ctx: &egui::Context, _full_output: &mut egui::FullOutput) {}
+}
+
+/// Which parts of the [`egui::Memory`] to persist, see [`App::persist_egui_memory_flags`].
+///
+/// Widget state that isn't covered by any flag is always persisted along with the rest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MemoryPersistFlags(u8);
+
+bitflags::bitflags! {
+    impl MemoryPersistFlags: u8 {
+        /// [`egui::Memory::options`], e.g. the style, zoom factor and theme.
+        const OPTIONS = 1 << 0;
+
+        /// Where windows and other areas are, and in what order.
+        const AREAS = 1 << 1;
+
+        /// Whether collapsing headers and windows are open.
+        const COLLAPSING = 1 << 2;
+
+        /// How far scroll areas are scrolled.
+        const SCROLL = 1 << 3;
+
+        /// Cursor and undo state of text edits.
+        const TEXT_EDIT = 1 << 4;
+    }
+}
+
+impl Default for MemoryPersistFlags {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl MemoryPersistFlags {
+    /// Remove the parts of `memory` that these flags don't select.
+    pub fn filter_memory(self, memory: &mut egui::Memory) {
+        if !self.contains(Self::OPTIONS) {
+            memory.options = Default::default();
+        }
+        if !self.contains(Self::AREAS) {
+            memory.reset_areas();
+            memory.to_global.clear();
+        }
+        if !self.contains(Self::COLLAPSING) {
+            egui::collapsing_header::CollapsingState::remove_all(&mut memory.data);
+        }
+        if !self.contains(Self::SCROLL) {
+            memory.data.remove_by_type::<egui::scroll_area::State>();
+        }
+        if !self.contains(Self::TEXT_EDIT) {
+            memory
+                .data
+                .remove_by_type::<egui::text_edit::TextEditState>();
+        }
+    }
+}
+
+/// What to do when a close of the root window is requested.
+///
+/// Returned by [`App::on_exit_requested`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExitAction {
+    /// Go ahead and close the window.
+    #[default]
+    Proceed,
+
+    /// Keep the window open.
+    Cancel,
 }
 
 /// Selects the level of hardware graphics acceleration.
@@ -320,6 +621,25 @@ pub struct NativeOptions {
     #[cfg(any(feature = "glow", feature = "wgpu"))]
     pub renderer: Renderer,
 
+    /// If the chosen [`Self::renderer`] fails to start, try the other one before giving up.
+    ///
+    /// This only has an effect when both the `glow` and `wgpu` features are enabled,
+    /// and only for errors where [`crate::Error::is_recoverable`] is `true`,
+    /// e.g. when there is no suitable `wgpu` adapter.
+    /// The event loop is reused for the second attempt, so the first attempt always
+    /// returns when the window closes. With [`Self::run_and_return`] set to `false`,
+    /// the process is then exited, just like without the fallback.
+    ///
+    /// Only [`crate::run_native`] (and functions built on it) fall back.
+    /// [`Self::window_builder`] is not called again for the fallback renderer.
+    ///
+    /// Creating windows with glow is not implemented yet, and fails with
+    /// [`crate::Error::Glutin`], so for now only the fallback from glow to wgpu can succeed.
+    ///
+    /// Default: `false`.
+    #[cfg(any(feature = "glow", feature = "wgpu"))]
+    pub renderer_fallback: bool,
+
     /// This controls what happens when you close the main eframe window.
     ///
     /// If `true`, execution will continue after the eframe window is closed.
@@ -334,6 +654,23 @@ pub struct NativeOptions {
     /// When `false`, [`winit::event_loop::EventLoop::run`] is used.
     pub run_and_return: bool,
 
+    /// If `true`, eframe saves the app state when the process is asked to terminate,
+    /// e.g. with Ctrl-C or `SIGTERM` on Unix, or by a console control event on Windows.
+    ///
+    /// On such a signal, [`App::save`] and [`App::on_exit`] are called, and then
+    /// eframe exits just as if the main window was closed (see [`Self::run_and_return`]).
+    /// A close that already happened is not saved twice.
+    ///
+    /// This replaces the default handling of these signals, which is to terminate right away.
+    ///
+    /// Does nothing on platforms without such signals.
+    ///
+    /// Requires the `os_signals` feature.
+    ///
+    /// Default: `false`.
+    #[cfg(feature = "os_signals")]
+    pub save_on_os_signal: bool,
+
     /// Hook into the building of an event loop before it is run.
     ///
     /// Specify a callback here in case you need to make platform specific changes to the
@@ -352,6 +689,14 @@ pub struct NativeOptions {
     #[cfg(any(feature = "glow", feature = "wgpu"))]
     pub window_builder: Option<WindowBuilderHook>,
 
+    /// Hook into the building of every window, including child viewports.
+    ///
+    /// Unlike [`Self::window_builder`], which only applies to the root window once,
+    /// this can be used to e.g. call `with_taskbar(false)` on tool windows.
+    /// It is applied after [`Self::window_builder`].
+    #[cfg(any(feature = "glow", feature = "wgpu"))]
+    pub viewport_builder_hook: Option<ViewportBuilderHook>,
+
     #[cfg(feature = "glow")]
     /// Needed for cross compiling for VirtualBox VMSVGA driver with OpenGL ES 2.0 and OpenGL 2.1 which doesn't support SRGB texture.
     /// See <https://github.com/emilk/egui/pull/1993>.
@@ -359,6 +704,38 @@ pub struct NativeOptions {
     /// For OpenGL ES 2.0: set this to [`egui_glow::ShaderVersion::Es100`] to solve blank texture problem (by using the "fallback shader").
     pub shader_version: Option<egui_glow::ShaderVersion>,
 
+    /// The window icon at several resolutions, e.g. 16, 32, 48 and 256 pixels.
+    ///
+    /// When set, eframe picks the best size for each place the platform shows the icon
+    /// (e.g. the title bar and task bar on Windows), instead of scaling
+    /// [`egui::ViewportBuilder::icon`].
+    /// Where only one size is supported, the closest larger icon is scaled down.
+    ///
+    /// See [`crate::icon_data::from_png_bytes_multi`].
+    pub icon_sizes: Vec<std::sync::Arc<egui::IconData>>,
+
+    /// If set, a system tray icon with this image is shown while the app is running.
+    ///
+    /// The tray icon stays even when the main window is hidden.
+    /// Supported on Windows, Linux and Mac.
+    #[cfg(feature = "tray_icon")]
+    pub tray_icon: Option<std::sync::Arc<egui::IconData>>,
+
+    /// The context menu of the [`Self::tray_icon`].
+    ///
+    /// When a [`TrayMenuItem::Item`] is clicked, [`App::on_tray_event`] is called with its `id`.
+    #[cfg(feature = "tray_icon")]
+    pub tray_menu: Option<Vec<TrayMenuItem>>,
+
+    /// What happens when the user closes the root window, e.g. with its close button.
+    ///
+    /// With [`CloseBehavior::HideToTray`] the window is hidden and the app keeps running,
+    /// until it is shown again with [`Frame::show_window`].
+    /// Sending [`egui::ViewportCommand::Close`] from the app always exits.
+    ///
+    /// Default: [`CloseBehavior::Exit`].
+    pub viewport_close_behavior: CloseBehavior,
+
     /// On desktop: make the window position to be centered at initialization.
     ///
     /// Platform specific:
@@ -366,14 +743,148 @@ pub struct NativeOptions {
     /// Wayland desktop currently not supported.
     pub centered: bool,
 
+    /// On desktop: center the window on this monitor at initialization,
+    /// instead of on the primary monitor.
+    ///
+    /// This is an index into the available monitors of the event loop, in the order the OS reports them.
+    /// If there is no such monitor, the primary monitor is used.
+    ///
+    /// Implies [`Self::centered`]. Wayland desktop currently not supported.
+    pub center_on_monitor: Option<usize>,
+
+    /// On desktop: use this scale factor instead of the one the OS reports for each monitor.
+    ///
+    /// This overrides the monitor's `scale_factor()` when eframe computes the initial
+    /// window size and position, e.g. for [`Self::centered`] and when clamping
+    /// the window to the size of the largest monitor.
+    /// It does not change how egui scales its content;
+    /// use [`egui::Context::set_zoom_factor`] for that.
+    ///
+    /// This is an escape hatch for systems that report an inconsistent scale factor,
+    /// e.g. some fractional-DPI Linux setups. Leave it at `None` unless you need it.
+    pub dpi_override: Option<f32>,
+
+    /// Whether the window starts out minimized, maximized, or fullscreen.
+    ///
+    /// Ignored if the window position and size is restored from a previous session
+    /// (see [`Self::persist_window`]): the saved state wins.
+    ///
+    /// Defaults to [`WindowStartupState::Normal`].
+    pub initial_window_state: WindowStartupState,
+
+    /// On desktop: keep the main window above all other windows.
+    ///
+    /// Same as [`egui::ViewportBuilder::with_always_on_top`] on [`Self::viewport`].
+    ///
+    /// Default: `false`.
+    pub always_on_top: bool,
+
+    /// On desktop: don't show the main window in the taskbar,
+    /// e.g. for utility windows and overlays.
+    ///
+    /// Same as [`egui::ViewportBuilder::with_taskbar`]`(false)` on [`Self::viewport`].
+    /// Only supported on Windows.
+    ///
+    /// Default: `false`.
+    pub skip_taskbar: bool,
+
+    /// On Linux: the window class used by the desktop environment
+    /// to group windows in the taskbar and match them with a `.desktop` file,
+    /// e.g. `"com.mycompany.myapp"`.
+    ///
+    /// This is the `WM_CLASS` property on X11 and the application ID on Wayland.
+    /// Overrides [`egui::ViewportBuilder::app_id`] of [`Self::viewport`] for the window,
+    /// but does not change where [`Storage`] is persisted.
+    ///
+    /// Default: `None`.
+    pub window_class_name: Option<String>,
+
+    /// Make the background of the main window transparent.
+    ///
+    /// Same as [`egui::ViewportBuilder::with_transparent`] on [`Self::viewport`].
+    /// The alpha of [`App::clear_color`] then controls how see-through the window is,
+    /// so you probably want a transparent [`egui::CentralPanel`] frame too.
+    ///
+    /// Default: `false`.
+    pub transparent: bool,
+
+    /// Blur whatever is behind the main window, for a frosted-glass look.
+    ///
+    /// This implies [`Self::transparent`].
+    /// Supported on macOS, Windows 11 (as an acrylic backdrop), and on Wayland with KDE.
+    /// Ignored elsewhere.
+    ///
+    /// Default: `false`.
+    pub blur_behind_window: bool,
+
+    /// Switch between [`egui::Visuals::dark`] and [`egui::Visuals::light`]
+    /// when the OS switches between dark and light mode.
+    ///
+    /// See also [`App::on_theme_changed`].
+    ///
+    /// Default: `false`.
+    pub follow_system_theme: bool,
+
     /// Configures wgpu instance/device/adapter/surface creation and renderloop.
     #[cfg(feature = "wgpu")]
     pub wgpu_options: egui_wgpu::WgpuConfiguration,
 
+    /// If `true`, the wgpu renderer paints on a separate render thread.
+    ///
+    /// The main thread then builds the next frame (running [`App::update`])
+    /// while the render thread still submits the previous one to the GPU.
+    /// At most one frame is in flight like this, so input latency grows by at most one frame.
+    ///
+    /// Screenshots and [`App::post_rendering`] may lag behind the actual painting by a frame.
+    ///
+    /// Only the painter moves to the render thread: your [`App`] stays on the main thread,
+    /// so it does not need to be `Send`.
+    ///
+    /// Ignored by the glow renderer.
+    ///
+    /// Default: `false`.
+    #[cfg(feature = "wgpu")]
+    pub multi_threaded_rendering: bool,
+
+    /// Cap how many frames per second eframe will paint, per window.
+    ///
+    /// When set, an immediate repaint (e.g. from [`egui::Context::request_repaint`] or from input)
+    /// less than `1.0 / frame_rate_limit` seconds after the previous frame
+    /// is postponed until that time.
+    /// A repaint scheduled with [`egui::Context::request_repaint_after`] is left untouched,
+    /// even if it comes sooner than the limit allows.
+    /// This never causes extra repaints: if nothing requests a repaint, eframe stays idle.
+    ///
+    /// Defaults to `None` (no limit).
+    pub frame_rate_limit: Option<f32>,
+
+    /// Force the event loop to always use this [`winit::event_loop::ControlFlow`].
+    ///
+    /// Normally eframe sleeps until there is an event or a repaint is due.
+    ///
+    /// * [`ControlFlow::Poll`](winit::event_loop::ControlFlow::Poll) keeps the event loop spinning,
+    ///   which minimizes the latency of repaints, but uses a lot more CPU.
+    ///   You still need to call [`egui::Context::request_repaint`] for continuous animation.
+    /// * [`ControlFlow::Wait`](winit::event_loop::ControlFlow::Wait) only wakes up on events,
+    ///   so a repaint scheduled with [`egui::Context::request_repaint_after`]
+    ///   won't happen until something else (e.g. mouse movement) wakes up the event loop.
+    ///
+    /// Defaults to `None` (let eframe decide).
+    #[cfg(any(feature = "glow", feature = "wgpu"))]
+    pub control_flow_override: Option<winit::event_loop::ControlFlow>,
+
     /// Controls whether or not the native window position and size will be
     /// persisted (only if the "persistence" feature is enabled).
     pub persist_window: bool,
 
+    /// If `true`, only the native window position and size will be persisted.
+    ///
+    /// Neither [`App::save`] nor the egui memory will be written to storage,
+    /// but the window geometry is still saved (and auto-saved) as usual.
+    ///
+    /// Defaults to `false`.
+    pub persist_window_only: bool,
+
     /// The folder where `eframe` will store the app state. If not set, eframe will use a default
     /// data storage path for each target system.
     pub persistence_path: Option<std::path::PathBuf>,
@@ -410,11 +921,24 @@ impl Clone for NativeOptions {
             #[cfg(any(feature = "glow", feature = "wgpu"))]
             window_builder: None, // Skip any builder callbacks if cloning
 
+            #[cfg(any(feature = "glow", feature = "wgpu"))]
+            viewport_builder_hook: self.viewport_builder_hook.clone(),
+
             #[cfg(feature = "wgpu")]
             wgpu_options: self.wgpu_options.clone(),
 
+            icon_sizes: self.icon_sizes.clone(),
+
+            #[cfg(feature = "tray_icon")]
+            tray_icon: self.tray_icon.clone(),
+
+            #[cfg(feature = "tray_icon")]
+            tray_menu: self.tray_menu.clone(),
+
             persistence_path: self.persistence_path.clone(),
 
+            window_class_name: self.window_class_name.clone(),
+
             #[cfg(target_os = "android")]
             android_app: self.android_app.clone(),
 
@@ -438,7 +962,12 @@ impl Default for NativeOptions {
             #[cfg(any(feature = "glow", feature = "wgpu"))]
             renderer: Renderer::default(),
 
+            #[cfg(any(feature = "glow", feature = "wgpu"))]
+            renderer_fallback: false,
+
             run_and_return: true,
+            #[cfg(feature = "os_signals")]
+            save_on_os_signal: false,
 
             #[cfg(any(feature = "glow", feature = "wgpu"))]
             event_loop_builder: None,
@@ -446,15 +975,45 @@ impl Default for NativeOptions {
             #[cfg(any(feature = "glow", feature = "wgpu"))]
             window_builder: None,
 
+            #[cfg(any(feature = "glow", feature = "wgpu"))]
+            viewport_builder_hook: None,
+
             #[cfg(feature = "glow")]
             shader_version: None,
 
+            icon_sizes: Vec::new(),
+
+            #[cfg(feature = "tray_icon")]
+            tray_icon: None,
+
+            #[cfg(feature = "tray_icon")]
+            tray_menu: None,
+
+            viewport_close_behavior: CloseBehavior::Exit,
+
             centered: false,
+            center_on_monitor: None,
+            dpi_override: None,
+            initial_window_state: WindowStartupState::Normal,
+            always_on_top: false,
+            skip_taskbar: false,
+            window_class_name: None,
+            transparent: false,
+            blur_behind_window: false,
+            follow_system_theme: false,
 
             #[cfg(feature = "wgpu")]
             wgpu_options: egui_wgpu::WgpuConfiguration::default(),
+            #[cfg(feature = "wgpu")]
+            multi_threaded_rendering: false,
+
+            frame_rate_limit: None,
+
+            #[cfg(any(feature = "glow", feature = "wgpu"))]
+            control_flow_override: None,
 
             persist_window: true,
+            persist_window_only: false,
 
             persistence_path: None,
 
@@ -466,6 +1025,117 @@ impl Default for NativeOptions {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "wgpu")]
+impl NativeOptions {
+    /// Set the [`wgpu::PowerPreference`] used to pick an adapter.
+    ///
+    /// Has no effect when [`Self::wgpu_options`] uses an existing wgpu setup.
+    #[inline]
+    pub fn with_wgpu_power_preference(mut self, power_preference: wgpu::PowerPreference) -> Self {
+        if let Some(create_new) = self.wgpu_setup_create_new() {
+            create_new.power_preference = power_preference;
+        }
+        self
+    }
+
+    /// Set which [`wgpu::Backends`] wgpu may pick from.
+    ///
+    /// Has no effect when [`Self::wgpu_options`] uses an existing wgpu setup.
+    #[inline]
+    pub fn with_wgpu_backends(mut self, backends: wgpu::Backends) -> Self {
+        if let Some(create_new) = self.wgpu_setup_create_new() {
+            create_new.instance_descriptor.backends = backends;
+        }
+        self
+    }
+
+    /// Request the wgpu device with this descriptor, e.g. to enable extra [`wgpu::Features`].
+    ///
+    /// This replaces eframe's default descriptor, including its raised texture size limit,
+    /// so start from [`wgpu::Limits`] that fit your needs.
+    ///
+    /// Has no effect when [`Self::wgpu_options`] uses an existing wgpu setup.
+    pub fn with_wgpu_device_descriptor(
+        mut self,
+        device_descriptor: wgpu::DeviceDescriptor<'static>,
+    ) -> Self {
+        if let Some(create_new) = self.wgpu_setup_create_new() {
+            create_new.device_descriptor =
+                std::sync::Arc::new(move |_adapter: &wgpu::Adapter| device_descriptor.clone());
+        }
+        self
+    }
+
+    fn wgpu_setup_create_new(&mut self) -> Option<&mut egui_wgpu::WgpuSetupCreateNew> {
+        match &mut self.wgpu_options.wgpu_setup {
+            egui_wgpu::WgpuSetup::CreateNew(create_new) => Some(create_new),
+            egui_wgpu::WgpuSetup::Existing(_) => {
+                log::warn!(
+                    "Ignoring wgpu setting, because NativeOptions::wgpu_options uses an existing wgpu setup"
+                );
+                None
+            }
+        }
+    }
+}
+
+/// See [`NativeOptions::initial_window_state`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowStartupState {
+    /// Use the size and position of [`NativeOptions::viewport`].
+    #[default]
+    Normal,
+
+    /// Start minimized to the taskbar or dock.
+    Minimized,
+
+    /// Start maximized.
+    Maximized,
+
+    /// Start in borderless fullscreen.
+    Fullscreen,
+}
+
+/// How a native app ended, returned by [`crate::run_native_with_exit_code`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExitResult {
+    /// Set with [`Frame::set_exit_code`]. Zero by default.
+    pub code: i32,
+}
+
+/// See [`NativeOptions::viewport_close_behavior`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CloseBehavior {
+    /// Close the window and exit the app.
+    #[default]
+    Exit,
+
+    /// Hide the window, but keep the app running, e.g. in the [`NativeOptions::tray_icon`].
+    HideToTray,
+}
+
+/// An entry in the [`NativeOptions::tray_menu`].
+#[cfg(all(feature = "tray_icon", not(target_arch = "wasm32")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TrayMenuItem {
+    /// A line of text that cannot be clicked.
+    Label(String),
+
+    /// A horizontal line separating groups of items.
+    Separator,
+
+    /// A clickable item.
+    ///
+    /// When clicked, [`App::on_tray_event`] is called with the given `id`.
+    Item { label: String, id: u32 },
+}
+
 // ----------------------------------------------------------------------------
 
 /// Options when using `eframe` in a web page.
@@ -509,6 +1179,42 @@ pub struct WebOptions {
     ///
     /// Defaults to true.
     pub should_prevent_default: Box<dyn Fn(&egui::Event) -> bool>,
+
+    /// Should this keyboard event be left entirely to the rest of the web page?
+    ///
+    /// If this returns `true`, the web event is neither `preventDefault`ed nor has its propagation stopped,
+    /// regardless of [`Self::should_stop_propagation`] and [`Self::should_prevent_default`].
+    /// egui still receives the event.
+    ///
+    /// Use this to let the browser or the host page handle some shortcuts, e.g. `ctrl-F`
+    /// or your own JavaScript hotkeys, when egui is embedded in a larger web app.
+    ///
+    /// Defaults to `None`.
+    pub should_propagate_event: Option<std::sync::Arc<dyn Fn(&egui::Event) -> bool>>,
+
+    /// Cap how many frames per second are painted, e.g. to save battery.
+    ///
+    /// When egui asks for a repaint sooner than `1.0 / max_fps` seconds after the previous frame,
+    /// the repaint is postponed to a later animation frame.
+    /// When no repaint is requested we stay idle, as usual.
+    ///
+    /// Defaults to `None` (repaint as fast as `requestAnimationFrame` allows).
+    pub max_fps: Option<f32>,
+
+    /// How the size of the canvas is decided.
+    ///
+    /// Defaults to [`CanvasResizePolicy::Window`].
+    pub canvas_resize_policy: CanvasResizePolicy,
+
+    /// Follow the light/dark preference of the OS (`prefers-color-scheme`),
+    /// and update live when the user changes it.
+    ///
+    /// The preference is reported as [`egui::RawInput::system_theme`],
+    /// which egui uses when the theme preference is [`egui::ThemePreference::System`].
+    /// An explicit theme set by the app (e.g. with [`egui::Context::set_theme`]) always wins.
+    ///
+    /// Defaults to `true`.
+    pub follow_system_theme: bool,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -527,6 +1233,13 @@ impl Default for WebOptions {
 
             should_stop_propagation: Box::new(|_| true),
             should_prevent_default: Box::new(|_| true),
+            should_propagate_event: None,
+
+            max_fps: None,
+
+            canvas_resize_policy: CanvasResizePolicy::default(),
+
+            follow_system_theme: true,
         }
     }
 }
@@ -552,6 +1265,24 @@ pub enum WebGlContextOption {
 
 // ----------------------------------------------------------------------------
 
+/// How the canvas is sized on the web, see [`WebOptions::canvas_resize_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CanvasResizePolicy {
+    /// The canvas is sized by the CSS of the web page, usually to fill the browser window,
+    /// and eframe follows whatever size it is given.
+    #[default]
+    Window,
+
+    /// The canvas is resized to fill its parent element, following it as it changes size.
+    Container,
+
+    /// The canvas has a fixed size, in CSS pixels.
+    Fixed { width: u32, height: u32 },
+}
+
+// ----------------------------------------------------------------------------
+
 /// What rendering backend to use.
 ///
 /// You need to enable the "glow" and "wgpu" features to have a choice.
@@ -636,9 +1367,24 @@ pub struct Frame {
     /// Information about the integration.
     pub(crate) info: IntegrationInfo,
 
+    /// Timing of the previous frame, see [`Self::frame_stats`].
+    pub(crate) frame_stats: FrameStats,
+
+    /// The monitor the app is on, updated each frame. See [`Self::current_monitor`].
+    pub(crate) monitor: Option<MonitorInfo>,
+
+    /// Updated each frame. See [`Self::screen_size_in_pixels`].
+    pub(crate) screen_size_in_pixels: Option<[u32; 2]>,
+
     /// A place where you can store custom data in a way that persists when you restart the app.
     pub(crate) storage: Option<Box<dyn Storage>>,
 
+    /// Overrides [`App::auto_save_interval`] when set.
+    pub(crate) auto_save_interval: Option<std::time::Duration>,
+
+    /// Set by [`Self::set_title`], sent as a [`egui::ViewportCommand::Title`] after [`App::update`].
+    pub(crate) title: Option<String>,
+
     /// A reference to the underlying [`glow`] (OpenGL) context.
     #[cfg(feature = "glow")]
     pub(crate) gl: Option<std::sync::Arc<glow::Context>>,
@@ -660,6 +1406,36 @@ pub struct Frame {
     /// Raw platform display handle for window
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) raw_display_handle: Result<RawDisplayHandle, HandleError>,
+
+    /// Set by [`Self::screenshot`] to ask the integration to capture the next painted frame.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) screenshot_requested: std::cell::Cell<bool>,
+
+    /// The latest captured frame, not yet returned by [`Self::screenshot`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) screenshot: std::cell::Cell<Option<egui::ColorImage>>,
+
+    /// Set by [`Self::request_screenshot`]: the integration calls [`Self::screenshot`]
+    /// until it returns the image, and passes it to [`App::on_screenshot`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) screenshot_to_app: std::cell::Cell<bool>,
+
+    /// Set by [`Self::request_user_attention`], sent as a [`egui::ViewportCommand::RequestUserAttention`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) user_attention: std::cell::Cell<Option<egui::UserAttentionType>>,
+
+    /// Set by [`Self::show_window`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) show_window: std::cell::Cell<bool>,
+
+    /// Set by [`Self::set_exit_code`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) exit_code: i32,
+
+    /// Returned by [`Self::event_loop_proxy`].
+    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(any(feature = "glow", feature = "wgpu"))]
+    pub(crate) user_event_sender: Option<UserEventSender>,
 }
 
 // Implementing `Clone` would violate the guarantees of `HasWindowHandle` and `HasDisplayHandle`.
@@ -694,13 +1470,33 @@ impl Frame {
             #[cfg(all(feature = "glow", not(target_arch = "wasm32")))]
             glow_register_native_texture: None,
             info: IntegrationInfo::mock(),
+            frame_stats: FrameStats::default(),
+            monitor: None,
+            screen_size_in_pixels: None,
             #[cfg(not(target_arch = "wasm32"))]
             raw_display_handle: Err(HandleError::NotSupported),
             #[cfg(not(target_arch = "wasm32"))]
             raw_window_handle: Err(HandleError::NotSupported),
             storage: None,
+            auto_save_interval: None,
+            title: None,
             #[cfg(feature = "wgpu")]
             wgpu_render_state: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            screenshot_requested: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            screenshot: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            screenshot_to_app: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            user_attention: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            show_window: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            exit_code: 0,
+            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(any(feature = "glow", feature = "wgpu"))]
+            user_event_sender: None,
         }
     }
 
@@ -717,6 +1513,39 @@ impl Frame {
         &self.info
     }
 
+    /// Seconds of cpu usage of each of the recent frames, oldest first.
+    ///
+    /// See [`IntegrationInfo::cpu_usage_history()`].
+    pub fn cpu_usage_history(&self) -> impl ExactSizeIterator<Item = f32> + '_ {
+        self.info.cpu_usage_history()
+    }
+
+    /// Timing statistics of the previous frame, e.g. for a live performance overlay.
+    pub fn frame_stats(&self) -> &FrameStats {
+        &self.frame_stats
+    }
+
+    /// The monitor the app is currently shown on, e.g. to compute its physical DPI.
+    ///
+    /// On web this describes the screen the browser window is on.
+    ///
+    /// `None` if the window hasn't been created yet, or the monitor is unknown.
+    pub fn current_monitor(&self) -> Option<MonitorInfo> {
+        self.monitor.clone()
+    }
+
+    /// The size of the root window's framebuffer in physical pixels, as `[width, height]`.
+    ///
+    /// This is also the size of screenshots of the root window,
+    /// which on high-DPI displays is larger than its size in points.
+    ///
+    /// On web this is the size of the canvas.
+    ///
+    /// `None` if the window hasn't been created yet.
+    pub fn screen_size_in_pixels(&self) -> Option<[u32; 2]> {
+        self.screen_size_in_pixels
+    }
+
     /// A place where you can store custom data in a way that persists when you restart the app.
     pub fn storage(&self) -> Option<&dyn Storage> {
         self.storage.as_deref()
@@ -727,6 +1556,139 @@ impl Frame {
         self.storage.as_deref_mut()
     }
 
+    /// Will [`App::save`] and egui memory actually be persisted?
+    ///
+    /// This is `false` if the "persistence" feature is off,
+    /// or if no storage could be created (e.g. there is no home directory to save to).
+    /// Use it to tell your users that their settings won't be saved.
+    pub fn storage_available(&self) -> bool {
+        self.storage.is_some()
+    }
+
+    /// Change the time between automatic calls to [`App::save`].
+    ///
+    /// This overrides [`App::auto_save_interval`] from the next auto-save check onward,
+    /// e.g. for a "save every N minutes" setting.
+    /// [`std::time::Duration::ZERO`] turns off auto-saving.
+    pub fn set_auto_save_interval(&mut self, interval: std::time::Duration) {
+        self.auto_save_interval = Some(interval);
+    }
+
+    /// A handle for sending your own events through the event loop to [`App::on_user_event`],
+    /// e.g. from a background thread.
+    ///
+    /// `None` when not running in a native event loop, e.g. in tests.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(any(feature = "glow", feature = "wgpu"))]
+    pub fn event_loop_proxy(&self) -> Option<UserEventSender> {
+        self.user_event_sender.clone()
+    }
+
+    /// Open the given url in the default browser, in a new tab if possible.
+    ///
+    /// On native this requires the `open-browser` feature.
+    /// If the url can't be opened (e.g. because there is no browser), a warning is logged.
+    #[expect(clippy::unused_self)]
+    pub fn open_url(&self, url: &str) {
+        #[cfg(target_arch = "wasm32")]
+        if crate::web::open_url(url, true).is_none() {
+            log::warn!("Failed to open url {url:?}");
+        }
+
+        #[cfg(all(not(target_arch = "wasm32"), feature = "open-browser"))]
+        if let Err(err) = webbrowser::open(url) {
+            log::warn!("Failed to open url {url:?}: {err}");
+        }
+
+        #[cfg(all(not(target_arch = "wasm32"), not(feature = "open-browser")))]
+        log::warn!("Cannot open url {url:?} - feature \"open-browser\" not enabled.");
+    }
+
+    /// Change the title of the root window (or of the web page).
+    ///
+    /// Same as [`Self::set_window_title`].
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.set_window_title(title);
+    }
+
+    /// Change the title of the root window (or of the web page).
+    ///
+    /// Shorthand for sending [`egui::ViewportCommand::Title`] to the root viewport,
+    /// but also updates the title eframe remembers for when it (re)sets the app icon,
+    /// so a later icon update won't revert to the old title.
+    /// The title is changed at the end of the current frame.
+    pub fn set_window_title(&mut self, title: impl Into<String>) {
+        self.title = Some(title.into());
+    }
+
+    /// Get the rendered contents of the root window as RGBA pixels.
+    ///
+    /// The frame can only be captured after it has been painted, so the first call
+    /// schedules a capture and returns `None`.
+    /// Keep calling this on the following frames until the image is returned.
+    /// Each captured image is returned only once.
+    ///
+    /// With the `glow` backend the image is read back right after the next frame is painted.
+    /// With `wgpu` the copy happens on the GPU and may take an extra frame to arrive.
+    ///
+    /// See also [`Self::request_screenshot`], which does the polling for you.
+    /// Don't use both at once, since they share the captured image.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn screenshot(&self) -> Option<egui::ColorImage> {
+        let image = self.screenshot.take();
+        if image.is_none() {
+            self.screenshot_requested.set(true);
+        }
+        image
+    }
+
+    /// Capture the root window once the current frame has been painted,
+    /// and deliver the image to [`App::on_screenshot`] on a following frame.
+    ///
+    /// This calls [`Self::screenshot`] for you every frame until the image is there,
+    /// so it works with both the `glow` and `wgpu` backends.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn request_screenshot(&self) {
+        self.screenshot_to_app.set(true);
+    }
+
+    /// Ask the user to look at the root window, e.g. by bouncing the dock icon on Mac
+    /// or flashing the taskbar button on Windows.
+    ///
+    /// This has no effect if the window already has focus.
+    /// Shorthand for sending [`egui::ViewportCommand::RequestUserAttention`] to the root viewport.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn request_user_attention(&self, kind: egui::UserAttentionType) {
+        self.user_attention.set(Some(kind));
+    }
+
+    /// Stop a previous [`Self::request_user_attention`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn cancel_user_attention(&self) {
+        self.request_user_attention(egui::UserAttentionType::Reset);
+    }
+
+    /// Show and focus the root window again,
+    /// e.g. after it was hidden by [`CloseBehavior::HideToTray`].
+    ///
+    /// Shorthand for sending [`egui::ViewportCommand::Visible`] and [`egui::ViewportCommand::Focus`]
+    /// to the root viewport.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn show_window(&self) {
+        self.show_window.set(true);
+    }
+
+    /// The process exit code to use once the app closes, e.g. for a CLI tool with a GUI.
+    ///
+    /// It is returned by [`crate::run_native_with_exit_code`] as [`ExitResult::code`].
+    /// With [`NativeOptions::run_and_return`] set to `false`, eframe exits the process with this code.
+    ///
+    /// Default: `0`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_exit_code(&mut self, code: i32) {
+        self.exit_code = code;
+    }
+
     /// A reference to the underlying [`glow`] (OpenGL) context.
     ///
     /// This can be used, for instance, to:
@@ -739,6 +1701,10 @@ impl Frame {
     ///
     /// To get a [`glow`] context you need to compile with the `glow` feature flag,
     /// and run eframe using [`Renderer::Glow`].
+    ///
+    /// This method only exists with the `glow` feature, since its return type comes from [`glow`].
+    /// A library that optionally supports glow can forward the feature
+    /// (e.g. `glow = ["eframe/glow"]` in its `Cargo.toml`), and gate its own code on it.
     #[cfg(feature = "glow")]
     pub fn gl(&self) -> Option<&std::sync::Arc<glow::Context>> {
         self.gl.as_ref()
@@ -758,6 +1724,10 @@ impl Frame {
     /// Only available when compiling with the `wgpu` feature and using [`Renderer::Wgpu`].
     ///
     /// Can be used to manage GPU resources for custom rendering with WGPU using [`egui::PaintCallback`]s.
+    ///
+    /// This method only exists with the `wgpu` feature, since its return type comes from [`egui_wgpu`].
+    /// A library that optionally supports wgpu can forward the feature
+    /// (e.g. `wgpu = ["eframe/wgpu"]` in its `Cargo.toml`), and gate its own code on it.
     #[cfg(feature = "wgpu")]
     pub fn wgpu_render_state(&self) -> Option<&egui_wgpu::RenderState> {
         self.wgpu_render_state.as_ref()
@@ -845,9 +1815,46 @@ pub struct IntegrationInfo {
     ///
     /// `None` if this is the first frame.
     pub cpu_usage: Option<f32>,
+
+    /// [`Self::cpu_usage`] of the last [`Self::CPU_USAGE_HISTORY_LEN`] frames, oldest first.
+    pub(crate) cpu_usage_history: std::collections::VecDeque<f32>,
+
+    /// Seconds spent in [`App::update`] on the most recent frame.
+    ///
+    /// `None` if this is the first frame.
+    pub update_time: Option<f32>,
+
+    /// Seconds spent tessellating the shapes of the previous frame into triangles.
+    ///
+    /// `None` if this is the first frame.
+    pub tessellation_time: Option<f32>,
+
+    /// Seconds spent painting the previous frame, not counting waiting for vsync.
+    ///
+    /// `None` if this is the first frame, or if painting happens on a separate render thread.
+    pub paint_time: Option<f32>,
 }
 
 impl IntegrationInfo {
+    /// How many frames [`Self::cpu_usage_history()`] remembers.
+    pub const CPU_USAGE_HISTORY_LEN: usize = 120;
+
+    /// [`Self::cpu_usage`] of each of the recent frames, oldest first.
+    ///
+    /// At most [`Self::CPU_USAGE_HISTORY_LEN`] values.
+    pub fn cpu_usage_history(&self) -> impl ExactSizeIterator<Item = f32> + '_ {
+        self.cpu_usage_history.iter().copied()
+    }
+
+    /// Called by the integration at the end of each frame.
+    pub(crate) fn report_cpu_usage(&mut self, seconds: f32) {
+        self.cpu_usage = Some(seconds);
+        if self.cpu_usage_history.len() == Self::CPU_USAGE_HISTORY_LEN {
+            self.cpu_usage_history.pop_front();
+        }
+        self.cpu_usage_history.push_back(seconds);
+    }
+
     fn mock() -> Self {
         Self {
             #[cfg(target_arch = "wasm32")]
@@ -866,12 +1873,87 @@ impl IntegrationInfo {
                 },
             },
             cpu_usage: None,
+            cpu_usage_history: Default::default(),
+            update_time: None,
+            tessellation_time: None,
+            paint_time: None,
         }
     }
 }
 
 // ----------------------------------------------------------------------------
 
+/// Information about a monitor, see [`Frame::current_monitor`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// The resolution of the monitor, in physical pixels.
+    pub size_px: [u32; 2],
+
+    /// Physical pixels per logical point, as set by the OS.
+    ///
+    /// On web this is `window.devicePixelRatio`, which includes the browser zoom.
+    pub scale_factor: f64,
+
+    /// A human-readable name of the monitor, if known.
+    pub name: Option<String>,
+
+    /// The refresh rate of the monitor, if known.
+    pub refresh_rate_hz: Option<f32>,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Timing statistics about the previous frame, see [`Frame::frame_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// Milliseconds of cpu usage of the previous frame.
+    ///
+    /// Same as [`IntegrationInfo::cpu_usage`], but in milliseconds.
+    pub cpu_time_ms: f32,
+
+    /// Milliseconds the GPU spent rendering the previous frame.
+    ///
+    /// `None` when the backend does not measure it, which is currently always the case.
+    pub gpu_time_ms: Option<f32>,
+
+    /// Seconds since the integration started, at the end of the previous frame.
+    pub total_elapsed_secs: f64,
+
+    /// The index of the previous frame, starting at zero.
+    pub frame_index: u64,
+
+    /// Frames per second, averaged over the last [`Self::FPS_WINDOW_LEN`] frames.
+    ///
+    /// Zero until at least two frames have been painted.
+    pub fps_smoothed: f32,
+}
+
+impl FrameStats {
+    /// How many frames [`Self::fps_smoothed`] is averaged over.
+    pub const FPS_WINDOW_LEN: usize = 60;
+
+    /// The rolling window of frame times used to compute [`Self::fps_smoothed`].
+    pub(crate) fn new_history() -> egui::util::History<f32> {
+        egui::util::History::new(0..Self::FPS_WINDOW_LEN, f32::INFINITY)
+    }
+
+    /// Called by the integration at the end of each frame.
+    pub(crate) fn report(
+        &mut self,
+        history: &mut egui::util::History<f32>,
+        elapsed_secs: f64,
+        cpu_usage_seconds: f32,
+    ) {
+        history.add(elapsed_secs, cpu_usage_seconds);
+        self.cpu_time_ms = 1e3 * cpu_usage_seconds;
+        self.total_elapsed_secs = elapsed_secs;
+        self.frame_index = history.total_count() - 1;
+        self.fps_smoothed = history.rate().unwrap_or(0.0);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A place where you can store custom data in a way that persists when you restart the app.
 ///
 /// On the web this is backed by [local storage](https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage).
@@ -887,22 +1969,161 @@ pub trait Storage {
 
     /// write-to-disk or similar
     fn flush(&mut self);
+
+    /// All keys that currently have a value.
+    ///
+    /// The default implementation returns an empty list.
+    fn list_keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Remove the value for the given key, if any.
+    ///
+    /// The default implementation does nothing.
+    fn delete_key(&mut self, _key: &str) {}
+
+    /// Remove all stored values.
+    ///
+    /// The default implementation does nothing.
+    fn clear(&mut self) {}
+
+    /// Dump all stored values as a human-readable string, e.g. for debugging.
+    ///
+    /// The default implementation writes one `key=value` line per key in [`Self::list_keys`],
+    /// which [`Self::import_from_str`] can read back.
+    /// Any `\`, `=`, newline or carriage return in the keys and values
+    /// is escaped as `\\`, `\=`, `\n` and `\r`.
+    ///
+    /// ```
+    /// use eframe::{Storage as _, storage::MemoryStorage};
+    ///
+    /// let storage = MemoryStorage::from_iter([
+    ///     ("b".to_owned(), "2".to_owned()),
+    ///     ("a".to_owned(), "(x: 1)".to_owned()),
+    /// ]);
+    /// assert_eq!(storage.export_to_string(), "a=(x: 1)\nb=2\n");
+    /// ```
+    fn export_to_string(&self) -> String {
+        let mut out = String::new();
+        for key in self.list_keys() {
+            if let Some(value) = self.get_string(&key) {
+                out += &format!(
+                    "{}={}\n",
+                    escape_storage_str(&key),
+                    escape_storage_str(&value)
+                );
+            }
+        }
+        out
+    }
+
+    /// Set the values in a string produced by [`Self::export_to_string`].
+    ///
+    /// Keys that are not in the string are left as they are.
+    /// Nothing is changed if the string is malformed.
+    ///
+    /// The default implementation reads escaped `key=value` lines, ignoring empty lines.
+    fn import_from_str(&mut self, s: &str) -> Result<(), StorageError> {
+        let pairs = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_storage_line(line)
+                    .map_err(|err| StorageError(format!("line {}: {err}", i + 1)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (key, value) in pairs {
+            self.set_string(&key, value);
+        }
+        Ok(())
+    }
+}
+
+/// Escape a key or value for [`Storage::export_to_string`].
+fn escape_storage_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out += "\\\\",
+            '=' => out += "\\=",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Parse an escaped `key=value` line written by [`Storage::export_to_string`].
+fn parse_storage_line(line: &str) -> Result<(String, String), String> {
+    let mut key = None;
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('\\') => current.push('\\'),
+                Some('=') => current.push('='),
+                Some('n') => current.push('\n'),
+                Some('r') => current.push('\r'),
+                Some(c) => return Err(format!("unknown escape `\\{c}`")),
+                None => return Err("unfinished escape at end of line".to_owned()),
+            },
+            '=' if key.is_none() => key = Some(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    let key = key.ok_or_else(|| "expected `key=value`".to_owned())?;
+    Ok((key, current))
+}
+
+/// Failed to [import](Storage::import_from_str) into a [`Storage`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageError(pub String);
+
+impl std::fmt::Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to import storage: {}", self.0)
+    }
 }
 
+impl std::error::Error for StorageError {}
+
 /// Get and deserialize the [RON](https://github.com/ron-rs/ron) stored at the given key.
+///
+/// Returns `None` if there is nothing stored, or if it could not be decoded.
+/// Use [`try_get_value`] to tell those apart.
 #[cfg(feature = "ron")]
 pub fn get_value<T: serde::de::DeserializeOwned>(storage: &dyn Storage, key: &str) -> Option<T> {
+    match try_get_value(storage, key) {
+        Ok(value) => value,
+        Err(err) => {
+            // This happens on when we break the format, e.g. when updating egui.
+            log::debug!("Failed to decode RON: {err}");
+            None
+        }
+    }
+}
+
+/// Like [`get_value`], but returns an error if the stored value could not be decoded,
+/// e.g. because the type changed since it was stored.
+///
+/// Returns `Ok(None)` if nothing is stored at the key.
+///
+/// # Errors
+/// If the stored value is not valid [RON](https://github.com/ron-rs/ron) for `T`.
+#[cfg(feature = "ron")]
+pub fn try_get_value<T: serde::de::DeserializeOwned>(
+    storage: &dyn Storage,
+    key: &str,
+) -> Result<Option<T>, ron::error::SpannedError> {
     profiling::function_scope!(key);
     storage
         .get_string(key)
-        .and_then(|value| match ron::from_str(&value) {
-            Ok(value) => Some(value),
-            Err(err) => {
-                // This happens on when we break the format, e.g. when updating egui.
-                log::debug!("Failed to decode RON: {err}");
-                None
-            }
-        })
+        .map(|value| ron::from_str(&value))
+        .transpose()
 }
 
 /// Serialize the given value as [RON](https://github.com/ron-rs/ron) and store with the given key.
@@ -917,3 +2138,87 @@ pub fn set_value<T: serde::Serialize>(storage: &mut dyn Storage, key: &str, valu
 
 /// [`Storage`] key used for app
 pub const APP_KEY: &str = "app";
+
+/// Should we auto-save now? See [`App::auto_save_interval`].
+pub(crate) fn is_auto_save_due(
+    time_since_last_save: std::time::Duration,
+    auto_save_interval: std::time::Duration,
+) -> bool {
+    !auto_save_interval.is_zero() && auto_save_interval < time_since_last_save
+}
+
+/// [`Storage`] key used for [`App::storage_version`].
+const STORAGE_VERSION_KEY: &str = "__app_version";
+
+/// Call [`App::migrate_storage`] if the stored version is older than [`App::storage_version`].
+pub(crate) fn migrate_storage(app: &mut dyn App, storage: &mut dyn Storage) {
+    let current_version = app.storage_version();
+    let stored_version = storage
+        .get_string(STORAGE_VERSION_KEY)
+        .and_then(|version| version.parse::<u32>().ok())
+        .unwrap_or(0);
+
+    if stored_version < current_version {
+        log::debug!("Migrating app storage from version {stored_version} to {current_version}");
+        app.migrate_storage(storage, stored_version);
+        storage.set_string(STORAGE_VERSION_KEY, current_version.to_string());
+        storage.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Storage as _, is_auto_save_due};
+    use crate::storage::MemoryStorage;
+
+    #[test]
+    fn auto_save_after_interval() {
+        let interval = Duration::from_secs(30);
+        assert!(!is_auto_save_due(Duration::from_secs(10), interval));
+        assert!(is_auto_save_due(Duration::from_secs(31), interval));
+    }
+
+    #[test]
+    fn zero_interval_disables_auto_save() {
+        assert!(!is_auto_save_due(Duration::ZERO, Duration::ZERO));
+        assert!(!is_auto_save_due(Duration::MAX, Duration::ZERO));
+    }
+
+    #[test]
+    fn export_import_roundtrip_with_special_characters() {
+        let storage = MemoryStorage::from_iter([
+            ("multi\nline".to_owned(), "(\n    x: 1,\n)\r\n".to_owned()),
+            ("a=b".to_owned(), "c=d".to_owned()),
+            (
+                "back\\slash".to_owned(),
+                "\\n is not a newline\\".to_owned(),
+            ),
+            ("empty".to_owned(), String::new()),
+        ]);
+
+        let exported = storage.export_to_string();
+        assert_eq!(exported.lines().count(), 4, "{exported}");
+
+        let mut imported = MemoryStorage::new();
+        imported.import_from_str(&exported).unwrap();
+        for key in storage.list_keys() {
+            assert_eq!(
+                imported.get_string(&key),
+                storage.get_string(&key),
+                "{key:?}"
+            );
+        }
+        assert_eq!(imported.list_keys(), storage.list_keys());
+    }
+
+    #[test]
+    fn import_rejects_malformed_lines() {
+        let mut storage = MemoryStorage::new();
+        assert!(storage.import_from_str("no separator").is_err());
+        assert!(storage.import_from_str("key=bad \\x escape").is_err());
+        assert!(storage.import_from_str("key=trailing\\").is_err());
+        assert!(storage.list_keys().is_empty());
+    }
+}
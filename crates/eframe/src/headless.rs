@@ -0,0 +1,56 @@
+//! Running an [`App`](crate::App) without a window or display server,
+//! e.g. for golden-image screenshot tests on a headless CI runner.
+//!
+//! Frames are painted with wgpu to an offscreen texture, so no surface is ever created.
+//! On machines without a GPU you need a software adapter such as lavapipe or WARP.
+//!
+//! For full control over the input of each frame, use [`crate::run_headless`].
+
+pub use crate::native::headless::HeadlessOptions;
+
+use crate::{AppCreator, Result};
+
+/// Run the app for `n_frames` frames, and return what it painted in each of them.
+///
+/// Each image is `width` x `height` pixels, at a scale of one pixel per point.
+/// The app gets no input besides the screen size and the passing of time.
+/// Fewer images are returned if the app closes itself early.
+///
+/// Time advances by a fixed 1/60 s per frame, regardless of how long painting takes,
+/// so animations produce the same images on every run.
+///
+/// ``` no_run
+/// # fn make_app(_cc: &eframe::CreationContext<'_>) -> Box<dyn eframe::App> { todo!() }
+/// let images = eframe::headless::run_headless(
+///     Box::new(|cc| Ok(make_app(cc))),
+///     640,
+///     480,
+///     3,
+/// )?;
+/// assert_eq!(images.len(), 3);
+/// assert_eq!(images[0].size, [640, 480]);
+/// # Ok::<(), eframe::Error>(())
+/// ```
+///
+/// # Errors
+/// If wgpu fails to find an adapter or device, if the app creator fails,
+/// or if reading back a rendered frame from the GPU fails.
+pub fn run_headless(
+    app_creator: AppCreator<'_>,
+    width: u32,
+    height: u32,
+    n_frames: usize,
+) -> Result<Vec<egui::ColorImage>> {
+    let options = HeadlessOptions {
+        size: egui::vec2(width as f32, height as f32),
+        pixels_per_point: 1.0,
+        ..Default::default()
+    };
+    const DT: f32 = 1.0 / 60.0;
+    let frames = (0..n_frames).map(|i| egui::RawInput {
+        time: Some(i as f64 * DT as f64),
+        predicted_dt: DT,
+        ..Default::default()
+    });
+    Ok(crate::run_headless(options, app_creator, frames)?.images)
+}
@@ -27,6 +27,197 @@ pub fn from_png_bytes(png_bytes: &[u8]) -> Result<IconData, image::ImageError> {
     Ok(from_image(image))
 }
 
+/// Encode an [`IconData`] as the contents of a .png file.
+///
+/// The inverse of [`from_png_bytes`]. Same as [`IconDataExt::to_png_bytes`],
+/// but with a more descriptive error for malformed icons.
+///
+/// # Errors
+/// If `rgba.len() != 4 * width * height`, or if the PNG encoder failed.
+pub fn to_png_bytes(icon: &IconData) -> Result<Vec<u8>, String> {
+    let expected_len = 4 * icon.width as usize * icon.height as usize;
+    if icon.rgba.len() != expected_len {
+        return Err(format!(
+            "Invalid IconData: a {}x{} icon needs {expected_len} bytes of RGBA, but got {}",
+            icon.width,
+            icon.height,
+            icon.rgba.len()
+        ));
+    }
+    icon.to_png_bytes()
+}
+
+/// Load several .png files containing the same icon at different resolutions.
+///
+/// The result is sorted from smallest to largest,
+/// and can be used for [`crate::NativeOptions::icon_sizes`].
+///
+/// # Errors
+/// If any of these is not a valid png.
+pub fn from_png_bytes_multi(png_bytes: &[&[u8]]) -> Result<Vec<IconData>, image::ImageError> {
+    profiling::function_scope!();
+    let mut icons = png_bytes
+        .iter()
+        .map(|png_bytes| from_png_bytes(png_bytes))
+        .collect::<Result<Vec<_>, _>>()?;
+    icons.sort_by_key(|icon| icon.width.max(icon.height));
+    Ok(icons)
+}
+
+/// Load all the images in the contents of an .ico file.
+///
+/// The result is sorted from smallest to largest,
+/// and can be used for [`crate::NativeOptions::icon_sizes`].
+///
+/// Requires the "ico" feature.
+///
+/// # Errors
+/// If this is not a valid ico, or one of its images could not be decoded.
+#[cfg(feature = "ico")]
+pub fn from_ico_bytes(ico_bytes: &[u8]) -> std::io::Result<Vec<IconData>> {
+    profiling::function_scope!();
+    let icon_dir = ico::IconDir::read(std::io::Cursor::new(ico_bytes))?;
+    let mut icons = icon_dir
+        .entries()
+        .iter()
+        .map(|entry| {
+            let image = entry.decode()?;
+            Ok(IconData {
+                width: image.width(),
+                height: image.height(),
+                rgba: image.rgba_data().to_vec(),
+            })
+        })
+        .collect::<std::io::Result<Vec<_>>>()?;
+    icons.sort_by_key(|icon| icon.width.max(icon.height));
+    Ok(icons)
+}
+
+/// Load the image from an .ico file best suited for an icon `width` pixels wide.
+///
+/// This is the largest image that is at most `width` wide (or else the largest one).
+/// Unlike [`best_fit`], the image is not resized.
+///
+/// Requires the "ico" feature.
+///
+/// # Errors
+/// If this is not a valid ico, or it contains no images.
+#[cfg(feature = "ico")]
+pub fn best_fit_from_ico(ico_bytes: &[u8], width: u32) -> std::io::Result<IconData> {
+    profiling::function_scope!();
+    let mut icons = from_ico_bytes(ico_bytes)?;
+    let index = icons
+        .iter()
+        .rposition(|icon| icon.width <= width)
+        .or_else(|| icons.len().checked_sub(1))
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "The .ico file is empty")
+        })?;
+    Ok(icons.swap_remove(index))
+}
+
+/// Pick the icon best suited to be shown at `size` x `size` pixels.
+///
+/// This is the smallest icon that is at least `size` large (or else the largest one),
+/// resized to exactly `size` x `size`.
+///
+/// Returns `None` if there are no valid icons.
+pub fn best_fit<'a>(icons: impl IntoIterator<Item = &'a IconData>, size: u32) -> Option<IconData> {
+    profiling::function_scope!();
+
+    let mut icons: Vec<&IconData> = icons.into_iter().collect();
+    icons.sort_by_key(|icon| icon.width.max(icon.height));
+
+    let icon = icons
+        .iter()
+        .find(|icon| size <= icon.width.min(icon.height))
+        .or_else(|| icons.last())?;
+
+    if icon.width == size && icon.height == size {
+        return Some((*icon).clone());
+    }
+
+    let image = icon.to_image().ok()?;
+    let resized = image::imageops::resize(&image, size, size, image::imageops::Lanczos3);
+    Some(from_image(resized.into()))
+}
+
+/// Load the contents of an .svg file, rasterized to an icon of `width` x `height` pixels.
+///
+/// The SVG is scaled to fit and centered, keeping its aspect ratio.
+///
+/// Requires the "svg" feature.
+///
+/// # Errors
+/// If this is not a valid svg, or `width` or `height` is zero.
+#[cfg(feature = "svg")]
+pub fn from_svg_bytes(svg_bytes: &[u8], width: u32, height: u32) -> Result<IconData, String> {
+    use resvg::{
+        tiny_skia::Pixmap,
+        usvg::{Options, Transform, Tree},
+    };
+
+    profiling::function_scope!();
+
+    let tree = Tree::from_data(svg_bytes, &Options::default()).map_err(|err| err.to_string())?;
+
+    let mut pixmap = Pixmap::new(width, height)
+        .ok_or_else(|| format!("Failed to create SVG Pixmap of size {width}x{height}"))?;
+
+    let source_size = tree.size();
+    let scale = (width as f32 / source_size.width()).min(height as f32 / source_size.height());
+    let offset_x = (width as f32 - scale * source_size.width()) / 2.0;
+    let offset_y = (height as f32 - scale * source_size.height()) / 2.0;
+
+    resvg::render(
+        &tree,
+        Transform::from_row(scale, 0.0, 0.0, scale, offset_x, offset_y),
+        &mut pixmap.as_mut(),
+    );
+
+    // `IconData` is not premultiplied:
+    let rgba = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+
+    Ok(IconData {
+        width,
+        height,
+        rgba,
+    })
+}
+
+/// Load the contents of an .svg file, rasterized to a square icon
+/// of `base_size` points on the primary monitor.
+///
+/// For instance, a `base_size` of 32 gives a 64x64 icon on a monitor with a scale factor of 2.
+///
+/// Monitors can only be queried once the event loop is running,
+/// e.g. from an [`crate::EframeWinitApplication`] driven by your own event loop.
+///
+/// Requires the "svg" feature.
+///
+/// # Errors
+/// If this is not a valid svg, or `base_size` is zero.
+#[cfg(feature = "svg")]
+pub fn from_svg_bytes_fitting_to_dpi(
+    svg_bytes: &[u8],
+    base_size: u32,
+    event_loop: &dyn winit::event_loop::ActiveEventLoop,
+) -> Result<IconData, String> {
+    let scale_factor = event_loop
+        .primary_monitor()
+        .or_else(|| event_loop.available_monitors().next())
+        .map_or(1.0, |monitor| monitor.scale_factor());
+    let size = (base_size as f64 * scale_factor).round() as u32;
+    from_svg_bytes(svg_bytes, size, size)
+}
+
 fn from_image(image: image::DynamicImage) -> IconData {
     let image = image.into_rgba8();
     IconData {
@@ -169,6 +169,8 @@ pub use epi::*;
 
 pub(crate) mod stopwatch;
 
+pub mod storage;
+
 // ----------------------------------------------------------------------------
 // When compiling for web
 
@@ -201,12 +203,19 @@ pub use native::run::EframePumpStatus;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(any(feature = "glow", feature = "wgpu"))]
-#[cfg(feature = "persistence")]
-pub use native::file_storage::storage_dir;
+pub use native::storage_dir::{storage_dir, storage_file};
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "wgpu")]
+pub use native::headless::{HeadlessOptions, HeadlessOutput};
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod icon_data;
 
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "wgpu")]
+pub mod headless;
+
 /// This is how you start a native (desktop) app.
 ///
 /// The first argument is name of your app, which is an identifier
@@ -253,14 +262,62 @@ pub mod icon_data;
 /// This function can fail if we fail to set up a graphics context.
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(any(feature = "glow", feature = "wgpu"))]
-#[allow(clippy::needless_pass_by_value, clippy::allow_attributes)]
 pub fn run_native(
     app_name: &str,
-    mut native_options: NativeOptions,
+    native_options: NativeOptions,
     app_creator: AppCreator<'_>,
 ) -> Result {
+    run_native_with_exit_code(app_name, native_options, app_creator).map(|_exit| ())
+}
+
+/// Like [`run_native`], but also returns the exit code the app set with [`Frame::set_exit_code`].
+///
+/// Useful for e.g. a CLI tool with a GUI, that wants to report its outcome to the shell.
+///
+/// ``` no_run
+/// # fn main() -> eframe::Result {
+/// # let app_creator: eframe::AppCreator<'_> = todo!();
+/// let exit = eframe::run_native_with_exit_code("MyApp", Default::default(), app_creator)?;
+/// std::process::exit(exit.code);
+/// # }
+/// ```
+///
+/// # Errors
+/// This function can fail if we fail to set up a graphics context.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+#[allow(clippy::needless_pass_by_value, clippy::allow_attributes)]
+pub fn run_native_with_exit_code(
+    app_name: &str,
+    mut native_options: NativeOptions,
+    app_creator: AppCreator<'_>,
+) -> Result<ExitResult> {
+    #[cfg(feature = "debug-storage")]
+    if std::env::args().skip(1).any(|arg| arg == "--dump-storage") {
+        dump_storage(app_name, &native_options);
+
+        #[expect(clippy::exit)]
+        std::process::exit(0);
+    }
+
     let renderer = init_native(app_name, &mut native_options);
 
+    #[cfg(all(feature = "glow", feature = "wgpu"))]
+    if native_options.renderer_fallback {
+        return run_native_with_fallback(app_name, renderer, native_options, app_creator);
+    }
+
+    run_native_with_renderer(app_name, renderer, native_options, app_creator)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+fn run_native_with_renderer(
+    app_name: &str,
+    renderer: Renderer,
+    native_options: NativeOptions,
+    app_creator: AppCreator<'_>,
+) -> Result<ExitResult> {
     match renderer {
         #[cfg(feature = "glow")]
         Renderer::Glow => {
@@ -276,6 +333,235 @@ pub fn run_native(
     }
 }
 
+/// Run with `renderer`, and if that fails to start, with the other renderer.
+///
+/// See [`NativeOptions::renderer_fallback`].
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "glow", feature = "wgpu"))]
+fn run_native_with_fallback(
+    app_name: &str,
+    renderer: Renderer,
+    native_options: NativeOptions,
+    app_creator: AppCreator<'_>,
+) -> Result<ExitResult> {
+    // The event loop is reused for the second attempt, so the first one must return:
+    let exit_when_closed = !native_options.run_and_return;
+
+    let result = with_renderer_fallback(renderer, app_creator, |renderer, app_creator| {
+        let mut native_options = native_options.clone();
+        native_options.renderer = renderer;
+        native_options.run_and_return = true;
+        run_native_with_renderer(app_name, renderer, native_options, app_creator)
+    });
+
+    if exit_when_closed {
+        if let Ok(exit) = &result {
+            log::debug!("Exiting with return code {}", exit.code);
+            #[expect(clippy::exit)]
+            std::process::exit(exit.code);
+        }
+    }
+    result
+}
+
+/// Call `run` with `renderer`, and if that fails before the app was created,
+/// call it again with the other renderer.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "glow", feature = "wgpu"))]
+fn with_renderer_fallback<'a>(
+    renderer: Renderer,
+    app_creator: AppCreator<'a>,
+    mut run: impl FnMut(Renderer, AppCreator<'a>) -> Result<ExitResult>,
+) -> Result<ExitResult> {
+    use std::{cell::RefCell, rc::Rc};
+
+    type SharedAppCreator<'app> = Rc<RefCell<Option<AppCreator<'app>>>>;
+
+    /// An [`AppCreator`] that takes the real one out of the shared slot when called.
+    fn take_from<'app>(shared: &SharedAppCreator<'app>) -> AppCreator<'app> {
+        let shared = shared.clone();
+        Box::new(move |cc| {
+            let app_creator = shared
+                .borrow_mut()
+                .take()
+                .ok_or("The app has already been created")?;
+            app_creator(cc)
+        })
+    }
+
+    let fallback = match renderer {
+        Renderer::Glow => Renderer::Wgpu,
+        Renderer::Wgpu => Renderer::Glow,
+    };
+
+    // The renderer is set up before the app is created, so if that fails,
+    // the app creator is still there for the second attempt.
+    let app_creator: SharedAppCreator<'a> = Rc::new(RefCell::new(Some(app_creator)));
+
+    match run(renderer, take_from(&app_creator)) {
+        Err(err) if err.is_recoverable() && app_creator.borrow().is_some() => {
+            log::warn!(
+                "Failed to start the {renderer} renderer: {err}. Falling back to the {fallback} renderer."
+            );
+            run(fallback, take_from(&app_creator))
+        }
+        result => result,
+    }
+}
+
+/// Like [`run_native`], but runs on an event loop you have built yourself.
+///
+/// Use this when you need full control over how the event loop is constructed,
+/// e.g. to pick X11 or Wayland, or to pass in the `AndroidApp`.
+/// [`NativeOptions::event_loop_builder`] and `NativeOptions::android_app` are ignored.
+///
+/// ``` no_run
+/// # fn main() -> eframe::Result {
+/// # let app_creator: eframe::AppCreator<'_> = todo!();
+/// let event_loop = winit::event_loop::EventLoop::builder().build()?;
+/// eframe::run_native_with_event_loop(
+///     "MyApp",
+///     eframe::NativeOptions::default(),
+///     app_creator,
+///     event_loop,
+/// )
+/// # }
+/// ```
+///
+/// # Errors
+/// This function can fail if we fail to set up a graphics context.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+pub fn run_native_with_event_loop(
+    app_name: &str,
+    mut native_options: NativeOptions,
+    app_creator: AppCreator<'_>,
+    event_loop: winit::event_loop::EventLoop,
+) -> Result {
+    let renderer = init_native(app_name, &mut native_options);
+
+    match renderer {
+        #[cfg(feature = "glow")]
+        Renderer::Glow => {
+            log::debug!("Using the glow renderer");
+            native::run::run_glow_with_event_loop(app_name, native_options, app_creator, event_loop)
+                .map(|_exit| ())
+        }
+
+        #[cfg(feature = "wgpu")]
+        Renderer::Wgpu => {
+            log::debug!("Using the wgpu renderer");
+            native::run::run_wgpu_with_event_loop(app_name, native_options, app_creator, event_loop)
+                .map(|_exit| ())
+        }
+    }
+}
+
+/// Like [`run_native`], but the app is created by a future.
+///
+/// Use this when creating your app requires async work, such as loading a config file or
+/// fetching an auth token. A loading spinner is shown until the future resolves.
+///
+/// `app_creator` is called with the [`CreationContext`] right away, and returns the future.
+/// Clone what the future needs (e.g. [`CreationContext::egui_ctx`]) into it.
+///
+/// The future is driven to completion on a background thread by a minimal executor.
+/// If it needs a specific runtime (e.g. `tokio`), spawn the work on that runtime
+/// and await the resulting handle in the future.
+///
+/// Until the app exists, the startup hooks [`App::on_storage_load_error`], [`App::migrate_storage`]
+/// and [`App::on_first_frame`] are held back, and they are called once it arrives.
+///
+/// ``` no_run
+/// # use eframe::egui;
+/// # struct MyEguiApp {}
+/// # impl eframe::App for MyEguiApp {
+/// #    fn update(&mut self, _ctx: &egui::Context, _frame: &mut eframe::Frame) {}
+/// # }
+/// # async fn load_config() -> String { String::new() }
+/// # fn main() -> eframe::Result {
+/// eframe::run_native_async("My egui App", Default::default(), |cc| {
+///     let egui_ctx = cc.egui_ctx.clone();
+///     Box::pin(async move {
+///         let _config = load_config().await;
+///         egui_ctx.set_zoom_factor(1.5);
+///         Ok(Box::new(MyEguiApp {}) as Box<dyn eframe::App + Send>)
+///     })
+/// })
+/// # }
+/// ```
+///
+/// # Errors
+/// This function can fail if we fail to set up a graphics context,
+/// or with [`Error::AppCreation`] if the future fails.
+/// In the latter case the window is closed, and if [`NativeOptions::run_and_return`] is `false`
+/// the process exits with code 1.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+pub fn run_native_async(
+    app_name: &str,
+    native_options: NativeOptions,
+    app_creator: impl FnOnce(&CreationContext<'_>) -> AppFuture,
+) -> Result {
+    let creation_error = std::rc::Rc::default();
+    run_native(
+        app_name,
+        native_options,
+        Box::new(|cc| {
+            Ok(Box::new(native::app_loader::AppLoader::new(
+                cc.egui_ctx.clone(),
+                app_creator(cc),
+                std::rc::Rc::clone(&creation_error),
+            )))
+        }),
+    )?;
+
+    match creation_error.take() {
+        Some(err) => Err(Error::AppCreation(err)),
+        None => Ok(()),
+    }
+}
+
+/// Run an [`App`] without opening a window, rendering each frame to an image.
+///
+/// Each [`egui::RawInput`] yielded by `frames` is fed to the app as one frame,
+/// and the result is painted with wgpu to an offscreen texture of
+/// [`HeadlessOptions::size`] points. The returned images are in the same order as the inputs.
+///
+/// If a [`egui::RawInput`] has no [`egui::RawInput::screen_rect`], it is set from the options.
+/// If it has no [`egui::RawInput::time`], the time since the start is used.
+///
+/// The app is run just like by [`run_native`]: it can load and save its state
+/// (see [`HeadlessOptions::persistence_path`]), set an exit code,
+/// and end the run early with [`egui::ViewportCommand::Close`].
+/// For the common case of just running a number of frames, see [`headless::run_headless`].
+///
+/// This makes golden-image testing of real apps possible:
+///
+/// ``` no_run
+/// # fn make_app(_cc: &eframe::CreationContext<'_>) -> Box<dyn eframe::App> { todo!() }
+/// let output = eframe::run_headless(
+///     eframe::HeadlessOptions::default(),
+///     Box::new(|cc| Ok(make_app(cc))),
+///     std::iter::repeat_with(eframe::egui::RawInput::default).take(3),
+/// )?;
+/// assert!(output.images.len() <= 3);
+/// # Ok::<(), eframe::Error>(())
+/// ```
+///
+/// # Errors
+/// This function can fail if we fail to set up wgpu, if the app creator fails,
+/// or if reading back a rendered frame from the GPU fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "wgpu")]
+pub fn run_headless(
+    options: HeadlessOptions,
+    app_creator: AppCreator<'_>,
+    frames: impl Iterator<Item = egui::RawInput>,
+) -> Result<HeadlessOutput> {
+    native::headless::run_headless(options, app_creator, frames)
+}
+
 /// Provides a proxy for your native eframe application to run on your own event loop.
 ///
 /// See `run_native` for details about `app_name`.
@@ -324,10 +610,34 @@ pub fn run_native(
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(any(feature = "glow", feature = "wgpu"))]
 pub fn create_native<'a>(
+    app_name: &str,
+    native_options: NativeOptions,
+    app_creator: AppCreator<'a>,
+    event_loop: &winit::event_loop::EventLoop,
+) -> EframeWinitApplication<'a> {
+    create_native_with_context(app_name, native_options, app_creator, event_loop, None)
+}
+
+/// Like [`create_native`], but optionally reusing an existing [`egui::Context`].
+///
+/// This is useful if you have already set up a context before creating the app,
+/// e.g. to load fonts or show a splash screen.
+/// The context is handed to your app in [`CreationContext::egui_ctx`].
+///
+/// eframe still turns off viewport embedding on desktop platforms,
+/// unless the context already has [`egui::Context::set_embed_viewports`] set to `false`.
+/// The context keeps its own [`egui::Options::max_passes`],
+/// and its memory is only replaced if there is some persisted memory to restore.
+///
+/// With `None`, this is the same as [`create_native`].
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+pub fn create_native_with_context<'a>(
     app_name: &str,
     mut native_options: NativeOptions,
     app_creator: AppCreator<'a>,
     event_loop: &winit::event_loop::EventLoop,
+    egui_ctx: Option<egui::Context>,
 ) -> EframeWinitApplication<'a> {
     let renderer = init_native(app_name, &mut native_options);
 
@@ -340,6 +650,7 @@ pub fn create_native<'a>(
                 native_options,
                 app_creator,
                 event_loop,
+                egui_ctx,
             ))
         }
 
@@ -351,11 +662,38 @@ pub fn create_native<'a>(
                 native_options,
                 app_creator,
                 event_loop,
+                egui_ctx,
             ))
         }
     }
 }
 
+/// Print the [`Storage::export_to_string`] of the storage [`run_native`] would use.
+///
+/// Used by the `--dump-storage` flag.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+#[cfg(feature = "debug-storage")]
+fn dump_storage(app_name: &str, native_options: &NativeOptions) {
+    let storage = if let Some(file) = &native_options.persistence_path {
+        native::epi_integration::create_storage_with_file(file)
+    } else {
+        native::epi_integration::create_storage(
+            native_options
+                .viewport
+                .app_id
+                .as_deref()
+                .unwrap_or(app_name),
+        )
+    };
+
+    if let Some(storage) = storage {
+        print!("{}", storage.export_to_string());
+    } else {
+        log::error!("No storage available for {app_name:?}");
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(any(feature = "glow", feature = "wgpu"))]
 fn init_native(app_name: &str, native_options: &mut NativeOptions) -> Renderer {
@@ -369,6 +707,11 @@ fn init_native(app_name: &str, native_options: &mut NativeOptions) -> Renderer {
         native_options.viewport.title = Some(app_name.to_owned());
     }
 
+    // Set this early, since both the window and the renderer need to know about it:
+    if native_options.transparent || native_options.blur_behind_window {
+        native_options.viewport.transparent = Some(true);
+    }
+
     let renderer = native_options.renderer;
 
     #[cfg(all(feature = "glow", feature = "wgpu"))]
@@ -424,23 +767,71 @@ fn init_native(app_name: &str, native_options: &mut NativeOptions) -> Renderer {
 pub fn run_simple_native(
     app_name: &str,
     native_options: NativeOptions,
-    update_fun: impl FnMut(&egui::Context, &mut Frame) + 'static,
+    mut update_fun: impl FnMut(&egui::Context, &mut Frame) + 'static,
 ) -> Result {
-    struct SimpleApp<U> {
+    run_simple_native_with_state(app_name, native_options, (), move |_, ctx, frame| {
+        update_fun(ctx, frame);
+    })
+}
+
+/// Like [`run_simple_native`], but with explicit state that is returned once the window closes.
+///
+/// The state is only returned if [`NativeOptions::run_and_return`] is `true` (the default),
+/// since otherwise the process exits when the window closes.
+///
+/// # Example
+/// ``` no_run
+/// fn main() -> eframe::Result {
+///     let options = eframe::NativeOptions::default();
+///     let name = eframe::run_simple_native_with_state(
+///         "My egui App",
+///         options,
+///         "Arthur".to_owned(),
+///         |name, ctx, _frame| {
+///             egui::CentralPanel::default().show(ctx, |ui| {
+///                 ui.text_edit_singleline(name);
+///             });
+///         },
+///     )?;
+///     println!("Hello '{name}'");
+///     Ok(())
+/// }
+/// ```
+///
+/// # Errors
+/// This function can fail if we fail to set up a graphics context.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+pub fn run_simple_native_with_state<S: 'static>(
+    app_name: &str,
+    native_options: NativeOptions,
+    state: S,
+    update_fun: impl FnMut(&mut S, &egui::Context, &mut Frame) + 'static,
+) -> Result<S> {
+    use std::{cell::RefCell, rc::Rc};
+
+    struct SimpleApp<S, U> {
+        state: Rc<RefCell<S>>,
         update_fun: U,
     }
 
-    impl<U: FnMut(&egui::Context, &mut Frame) + 'static> App for SimpleApp<U> {
+    impl<S, U: FnMut(&mut S, &egui::Context, &mut Frame)> App for SimpleApp<S, U> {
         fn update(&mut self, ctx: &egui::Context, frame: &mut Frame) {
-            (self.update_fun)(ctx, frame);
+            (self.update_fun)(&mut self.state.borrow_mut(), ctx, frame);
         }
     }
 
-    run_native(
-        app_name,
-        native_options,
-        Box::new(|_cc| Ok(Box::new(SimpleApp { update_fun }))),
-    )
+    let state = Rc::new(RefCell::new(state));
+
+    run_native(app_name, native_options, {
+        let state = state.clone();
+        Box::new(|_cc| Ok(Box::new(SimpleApp { state, update_fun })))
+    })?;
+
+    // The app has been dropped by now, so we hold the only reference:
+    Rc::try_unwrap(state)
+        .map(RefCell::into_inner)
+        .map_err(|_state| Error::AppCreation("The app state is still in use".into()))
 }
 
 // ----------------------------------------------------------------------------
@@ -476,7 +867,67 @@ pub enum Error {
     Wgpu(egui_wgpu::WgpuError),
 }
 
-impl std::error::Error for Error {}
+impl Error {
+    /// Could retrying with a different renderer succeed?
+    ///
+    /// This is `true` for failures to set up the graphics context (e.g. no matching OpenGL config,
+    /// or no suitable `wgpu` adapter), and `false` for errors from your own app creation,
+    /// or from the windowing system itself.
+    ///
+    /// ``` no_run
+    /// # fn run_with_wgpu() -> eframe::Result { Ok(()) }
+    /// # fn run_with_glow() -> eframe::Result { Ok(()) }
+    /// let result = match run_with_wgpu() {
+    ///     Err(err) if err.is_recoverable() => run_with_glow(),
+    ///     result => result,
+    /// };
+    /// ```
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            Self::AppCreation(_) => false,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Winit(_) | Self::WinitEventLoop(_) => false,
+
+            #[cfg(all(feature = "glow", not(target_arch = "wasm32")))]
+            Self::Glutin(_) | Self::NoGlutinConfigs(..) => true,
+
+            #[cfg(feature = "glow")]
+            Self::OpenGL(_) => true,
+
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => true,
+        }
+    }
+}
+
+/// [`Error`] already includes the message of the error it wraps,
+/// so the chain continues with the source of that error.
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AppCreation(err) => err.source(),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Winit(err) => err.source(),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::WinitEventLoop(err) => err.source(),
+
+            #[cfg(all(feature = "glow", not(target_arch = "wasm32")))]
+            Self::Glutin(err) => err.source(),
+
+            #[cfg(all(feature = "glow", not(target_arch = "wasm32")))]
+            Self::NoGlutinConfigs(_, err) => err.source(),
+
+            #[cfg(feature = "glow")]
+            Self::OpenGL(err) => err.source(),
+
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(err) => err.source(),
+        }
+    }
+}
 
 #[cfg(not(target_arch = "wasm32"))]
 impl From<winit::error::RequestError> for Error {
@@ -561,3 +1012,75 @@ impl std::fmt::Display for Error {
 
 /// Short for `Result<T, eframe::Error>`.
 pub type Result<T = (), E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "glow", feature = "wgpu"))]
+mod tests {
+    use super::{AppCreator, CreationContext, Error, ExitResult, Renderer, with_renderer_fallback};
+
+    struct EmptyApp;
+
+    impl crate::App for EmptyApp {
+        fn update(&mut self, _ctx: &egui::Context, _frame: &mut crate::Frame) {}
+    }
+
+    fn not_supported() -> Error {
+        Error::Glutin(glutin::error::ErrorKind::NotSupported("test").into())
+    }
+
+    fn create_app(app_creator: AppCreator<'_>) -> crate::Result<ExitResult> {
+        let cc = CreationContext::new_for_tests(egui::Context::default());
+        app_creator(&cc).map_err(Error::AppCreation)?;
+        Ok(ExitResult::default())
+    }
+
+    #[test]
+    fn falls_back_when_the_renderer_fails_to_start() {
+        let mut attempts = Vec::new();
+        let result = with_renderer_fallback(
+            Renderer::Glow,
+            Box::new(|_cc| Ok(Box::new(EmptyApp))),
+            |renderer, app_creator| {
+                attempts.push(renderer);
+                match renderer {
+                    Renderer::Glow => Err(not_supported()),
+                    Renderer::Wgpu => create_app(app_creator),
+                }
+            },
+        );
+        assert!(result.is_ok());
+        assert_eq!(attempts, [Renderer::Glow, Renderer::Wgpu]);
+    }
+
+    #[test]
+    fn no_fallback_when_the_app_creator_fails() {
+        let mut attempts = Vec::new();
+        let result = with_renderer_fallback(
+            Renderer::Wgpu,
+            Box::new(|_cc| Err("failing creator".into())),
+            |renderer, app_creator| {
+                attempts.push(renderer);
+                create_app(app_creator)
+            },
+        );
+        assert!(matches!(result, Err(Error::AppCreation(_))));
+        assert_eq!(attempts, [Renderer::Wgpu]);
+    }
+
+    #[test]
+    fn no_fallback_after_the_app_was_created() {
+        let mut attempts = Vec::new();
+        let result = with_renderer_fallback(
+            Renderer::Wgpu,
+            Box::new(|_cc| Ok(Box::new(EmptyApp))),
+            |renderer, app_creator| {
+                attempts.push(renderer);
+                create_app(app_creator)?;
+                Err(not_supported())
+            },
+        );
+        assert!(matches!(result, Err(Error::Glutin(_))));
+        assert_eq!(attempts, [Renderer::Wgpu]);
+    }
+}
@@ -8,29 +8,44 @@ use egui::IconData;
 
 pub struct AppTitleIconSetter {
     title: String,
-    icon_data: Option<Arc<IconData>>,
+
+    /// The same icon at one or more resolutions, sorted from smallest to largest.
+    icons: Vec<Arc<IconData>>,
+
     status: AppIconStatus,
 }
 
 impl AppTitleIconSetter {
-    pub fn new(title: String, mut icon_data: Option<Arc<IconData>>) -> Self {
-        if let Some(icon) = &icon_data {
-            if **icon == IconData::default() {
-                icon_data = None;
-            }
-        }
+    /// If `icon_sizes` is non-empty, it is used instead of `icon_data`.
+    pub fn new(
+        title: String,
+        icon_data: Option<Arc<IconData>>,
+        icon_sizes: Vec<Arc<IconData>>,
+    ) -> Self {
+        let mut icons = if icon_sizes.is_empty() {
+            icon_data.into_iter().collect()
+        } else {
+            icon_sizes
+        };
+        icons.retain(|icon| **icon != IconData::default());
+        icons.sort_by_key(|icon| icon.width.max(icon.height));
 
         Self {
             title,
-            icon_data,
+            icons,
             status: AppIconStatus::NotSetTryAgain,
         }
     }
 
+    /// Change the title that is set together with the icon.
+    pub fn set_title(&mut self, title: String) {
+        self.title = title;
+    }
+
     /// Call once per frame; we will set the icon when we can.
     pub fn update(&mut self) {
         if self.status == AppIconStatus::NotSetTryAgain {
-            self.status = set_title_and_icon(&self.title, self.icon_data.as_deref());
+            self.status = set_title_and_icon(&self.title, &self.icons);
         }
     }
 }
@@ -58,18 +73,20 @@ enum AppIconStatus {
 ///
 /// Since window creation can be lazy, call this every frame until it's either successfully or gave up.
 /// (See [`AppIconStatus`])
-fn set_title_and_icon(_title: &str, _icon_data: Option<&IconData>) -> AppIconStatus {
+///
+/// `_icons` is the same icon at one or more resolutions, sorted from smallest to largest.
+fn set_title_and_icon(_title: &str, _icons: &[Arc<IconData>]) -> AppIconStatus {
     profiling::function_scope!();
 
     #[cfg(target_os = "windows")]
     {
-        if let Some(icon_data) = _icon_data {
-            return set_app_icon_windows(icon_data);
+        if !_icons.is_empty() {
+            return set_app_icon_windows(_icons);
         }
     }
 
     #[cfg(target_os = "macos")]
-    return set_title_and_icon_mac(_title, _icon_data);
+    return set_title_and_icon_mac(_title, _icons.last().map(|icon| &**icon));
 
     #[allow(unreachable_code, clippy::allow_attributes)]
     AppIconStatus::NotSetIgnored
@@ -78,7 +95,7 @@ fn set_title_and_icon(_title: &str, _icon_data: Option<&IconData>) -> AppIconSta
 /// Set icon for Windows applications.
 #[cfg(target_os = "windows")]
 #[expect(unsafe_code)]
-fn set_app_icon_windows(icon_data: &IconData) -> AppIconStatus {
+fn set_app_icon_windows(icons: &[Arc<IconData>]) -> AppIconStatus {
     use crate::icon_data::IconDataExt as _;
     use winapi::um::winuser;
 
@@ -139,12 +156,11 @@ fn set_app_icon_windows(icon_data: &IconData) -> AppIconStatus {
         }
     }
 
-    let unscaled_image = match icon_data.to_image() {
-        Ok(unscaled_image) => unscaled_image,
-        Err(err) => {
-            log::warn!("Invalid icon: {err}");
-            return AppIconStatus::NotSetIgnored;
-        }
+    // Start from the best matching resolution for each size:
+    let unscaled_image_for_size = |target_size: i32| {
+        let icon = crate::icon_data::best_fit(icons.iter().map(|icon| &**icon), target_size as _)
+            .ok_or_else(|| "No icon".to_owned())?;
+        icon.to_image()
     };
 
     // Only setting ICON_BIG with the icon size for big icons (SM_CXICON) works fine
@@ -156,6 +172,13 @@ fn set_app_icon_windows(icon_data: &IconData) -> AppIconStatus {
     {
         // SAFETY: WinAPI getter function with no known side effects.
         let icon_size_big = unsafe { winuser::GetSystemMetrics(winuser::SM_CXICON) };
+        let unscaled_image = match unscaled_image_for_size(icon_size_big) {
+            Ok(unscaled_image) => unscaled_image,
+            Err(err) => {
+                log::warn!("Invalid icon: {err}");
+                return AppIconStatus::NotSetIgnored;
+            }
+        };
         let icon_big = create_hicon_with_scale(&unscaled_image, icon_size_big);
         if icon_big.is_null() {
             log::warn!("Failed to create HICON (for big icon) from embedded png data.");
@@ -175,6 +198,13 @@ fn set_app_icon_windows(icon_data: &IconData) -> AppIconStatus {
     {
         // SAFETY: WinAPI getter function with no known side effects.
         let icon_size_small = unsafe { winuser::GetSystemMetrics(winuser::SM_CXSMICON) };
+        let unscaled_image = match unscaled_image_for_size(icon_size_small) {
+            Ok(unscaled_image) => unscaled_image,
+            Err(err) => {
+                log::warn!("Invalid icon: {err}");
+                return AppIconStatus::NotSetIgnored;
+            }
+        };
         let icon_small = create_hicon_with_scale(&unscaled_image, icon_size_small);
         if icon_small.is_null() {
             log::warn!("Failed to create HICON (for small icon) from embedded png data.");
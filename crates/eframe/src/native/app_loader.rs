@@ -0,0 +1,305 @@
+//! Shows a loading spinner while an [`epi::AppFuture`] creates the real app.
+
+use std::{
+    cell::Cell,
+    future::Future,
+    rc::Rc,
+    sync::{Arc, mpsc},
+    task::{Poll, Wake, Waker},
+};
+
+use crate::epi;
+
+type AppResult = Result<Box<dyn epi::App + Send>, epi::DynError>;
+
+/// Wraps the app created by an [`epi::AppFuture`].
+///
+/// Until the future resolves, a spinner is shown.
+/// After that, everything is forwarded to the created app.
+///
+/// The startup hooks that eframe calls before the app exists
+/// ([`epi::App::on_storage_load_error`], [`epi::App::migrate_storage`] and [`epi::App::on_first_frame`])
+/// are replayed to the app once it arrives.
+pub struct AppLoader {
+    egui_ctx: egui::Context,
+    receiver: Option<mpsc::Receiver<AppResult>>,
+    app: Option<Box<dyn epi::App + Send>>,
+    error: Option<String>,
+
+    /// If the app could not be created, the error is put here and the window is closed.
+    creation_error: Rc<Cell<Option<epi::DynError>>>,
+
+    /// Calls to [`epi::App::on_storage_load_error`] from before the app arrived.
+    storage_load_errors: Vec<(String, epi::DynError)>,
+
+    /// [`epi::App::on_first_frame`] was called before the app arrived.
+    first_frame_pending: bool,
+}
+
+impl AppLoader {
+    /// Start driving the future on a background thread.
+    ///
+    /// If the future fails, the window is closed and the error is put in `creation_error`.
+    pub fn new(
+        egui_ctx: egui::Context,
+        app_future: epi::AppFuture,
+        creation_error: Rc<Cell<Option<epi::DynError>>>,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        let spawn_result = std::thread::Builder::new()
+            .name("eframe_app_loader".to_owned())
+            .spawn({
+                let egui_ctx = egui_ctx.clone();
+                let sender = sender.clone();
+                move || {
+                    let result = block_on(app_future);
+                    sender.send(result).ok();
+                    egui_ctx.request_repaint();
+                }
+            });
+        if let Err(err) = spawn_result {
+            let err = format!("Failed to spawn app loader thread: {err}");
+            sender.send(Err(err.into())).ok();
+        }
+
+        Self {
+            egui_ctx,
+            receiver: Some(receiver),
+            app: None,
+            error: None,
+            creation_error,
+            storage_load_errors: Vec::new(),
+            first_frame_pending: false,
+        }
+    }
+
+    fn poll(&mut self, frame: &mut epi::Frame) {
+        let Some(receiver) = &self.receiver else {
+            return;
+        };
+
+        let err = match receiver.try_recv() {
+            Ok(Ok(app)) => {
+                self.receiver = None;
+                self.on_app_created(app, frame);
+                return;
+            }
+            Ok(Err(err)) => err,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => "The app future panicked".into(),
+        };
+
+        log::error!("Failed to create app: {err}");
+        self.error = Some(err.to_string());
+        self.creation_error.set(Some(err));
+        self.receiver = None;
+        frame.set_exit_code(1);
+        self.egui_ctx
+            .send_viewport_cmd(egui::ViewportCommand::Close);
+    }
+
+    /// Replay the startup hooks the app missed while it was being created.
+    fn on_app_created(&mut self, mut app: Box<dyn epi::App + Send>, frame: &mut epi::Frame) {
+        for (key, err) in self.storage_load_errors.drain(..) {
+            app.on_storage_load_error(&key, &*err);
+        }
+        if let Some(storage) = frame.storage_mut() {
+            epi::migrate_storage(app.as_mut(), storage);
+        }
+        self.app = Some(app);
+    }
+}
+
+impl epi::App for AppLoader {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut epi::Frame) {
+        self.poll(frame);
+
+        if let Some(app) = &mut self.app {
+            app.update(ctx, frame);
+            return;
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.centered_and_justified(|ui| {
+                if let Some(error) = &self.error {
+                    ui.colored_label(ui.visuals().error_fg_color, error.as_str());
+                } else {
+                    ui.spinner();
+                }
+            });
+        });
+    }
+
+    fn save(&mut self, storage: &mut dyn epi::Storage) {
+        if let Some(app) = &mut self.app {
+            app.save(storage);
+        }
+    }
+
+    fn on_drop_files(&mut self, ctx: &egui::Context, paths: &[std::path::PathBuf]) {
+        if let Some(app) = &mut self.app {
+            app.on_drop_files(ctx, paths);
+        }
+    }
+
+    fn on_focus_changed(&mut self, ctx: &egui::Context, focused: bool) {
+        if let Some(app) = &mut self.app {
+            app.on_focus_changed(ctx, focused);
+        }
+    }
+
+    fn on_theme_changed(&mut self, ctx: &egui::Context, dark: bool) {
+        if let Some(app) = &mut self.app {
+            app.on_theme_changed(ctx, dark);
+        }
+    }
+
+    fn on_storage_load_error(&mut self, key: &str, err: &dyn std::error::Error) {
+        if let Some(app) = &mut self.app {
+            app.on_storage_load_error(key, err);
+        } else {
+            self.storage_load_errors
+                .push((key.to_owned(), err.to_string().into()));
+        }
+    }
+
+    fn on_screenshot(&mut self, image: egui::ColorImage) {
+        if let Some(app) = &mut self.app {
+            app.on_screenshot(image);
+        }
+    }
+
+    #[cfg(feature = "tray_icon")]
+    fn on_tray_event(&mut self, ctx: &egui::Context, id: u32) {
+        if let Some(app) = &mut self.app {
+            app.on_tray_event(ctx, id);
+        }
+    }
+
+    fn on_user_event(&mut self, ctx: &egui::Context, event: &dyn std::any::Any) {
+        if let Some(app) = &mut self.app {
+            app.on_user_event(ctx, event);
+        }
+    }
+
+    fn on_suspend(&mut self, ctx: &egui::Context) {
+        if let Some(app) = &mut self.app {
+            app.on_suspend(ctx);
+        }
+    }
+
+    fn on_resume(&mut self, ctx: &egui::Context) {
+        if let Some(app) = &mut self.app {
+            app.on_resume(ctx);
+        }
+    }
+
+    fn on_exit_requested(&mut self, ctx: &egui::Context) -> epi::ExitAction {
+        self.app
+            .as_mut()
+            .map_or(epi::ExitAction::Proceed, |app| app.on_exit_requested(ctx))
+    }
+
+    #[cfg(feature = "glow")]
+    fn on_exit(&mut self, gl: Option<&glow::Context>) {
+        if let Some(app) = &mut self.app {
+            app.on_exit(gl);
+        }
+    }
+
+    #[cfg(not(feature = "glow"))]
+    fn on_exit(&mut self) {
+        if let Some(app) = &mut self.app {
+            app.on_exit();
+        }
+    }
+
+    fn auto_save_interval(&self) -> std::time::Duration {
+        self.app.as_ref().map_or_else(
+            || std::time::Duration::from_secs(30),
+            |app| app.auto_save_interval(),
+        )
+    }
+
+    fn clear_color(&self, visuals: &egui::Visuals) -> [f32; 4] {
+        self.app.as_ref().map_or_else(
+            || visuals.panel_fill.to_normalized_gamma_f32(),
+            |app| app.clear_color(visuals),
+        )
+    }
+
+    fn persist_egui_memory(&self) -> bool {
+        self.app
+            .as_ref()
+            .is_none_or(|app| app.persist_egui_memory())
+    }
+
+    fn persist_egui_memory_flags(&self) -> epi::MemoryPersistFlags {
+        self.app
+            .as_ref()
+            .map_or_else(epi::MemoryPersistFlags::all, |app| {
+                app.persist_egui_memory_flags()
+            })
+    }
+
+    fn pre_frame(&mut self, ctx: &egui::Context, frame: &mut epi::Frame) {
+        if let Some(app) = &mut self.app {
+            app.pre_frame(ctx, frame);
+        }
+    }
+
+    fn raw_input_hook(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        if let Some(app) = &mut self.app {
+            app.raw_input_hook(ctx, raw_input);
+        }
+    }
+
+    fn on_first_frame(&mut self, ctx: &egui::Context, frame: &mut epi::Frame) {
+        if let Some(app) = &mut self.app {
+            app.on_first_frame(ctx, frame);
+        } else {
+            self.first_frame_pending = true;
+        }
+    }
+
+    fn post_rendering(&mut self, window_size_px: [u32; 2], frame: &mut epi::Frame) {
+        if let Some(app) = &mut self.app {
+            // The first frame of the app itself has now been painted:
+            if std::mem::take(&mut self.first_frame_pending) {
+                app.on_first_frame(&self.egui_ctx, frame);
+            }
+            app.post_rendering(window_size_px, frame);
+        }
+    }
+
+    fn raw_output_hook(&mut self, ctx: &egui::Context, full_output: &mut egui::FullOutput) {
+        if let Some(app) = &mut self.app {
+            app.raw_output_hook(ctx, full_output);
+        }
+    }
+}
+
+/// A minimal executor: poll the future on the current thread, parking it while pending.
+///
+/// Futures that need a specific runtime (e.g. `tokio`) should be spawned on that runtime,
+/// and the resulting handle awaited instead.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
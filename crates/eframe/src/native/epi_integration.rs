@@ -5,7 +5,7 @@ use web_time::Instant;
 use std::path::PathBuf;
 use winit::event_loop::ActiveEventLoop;
 
-use raw_window_handle::{HasDisplayHandle as _, HasWindowHandle as _};
+use raw_window_handle::{HandleError, HasDisplayHandle as _, HasWindowHandle as _};
 
 use egui::{DeferredViewportUiCallback, ViewportBuilder, ViewportId};
 use egui_winit::{EventResponse, WindowSettings};
@@ -18,15 +18,47 @@ pub fn viewport_builder(
     event_loop: &dyn ActiveEventLoop,
     native_options: &mut epi::NativeOptions,
     window_settings: Option<WindowSettings>,
+    monitor_name: Option<&str>,
 ) -> ViewportBuilder {
     profiling::function_scope!();
 
     let mut viewport_builder = native_options.viewport.clone();
 
+    if native_options.always_on_top {
+        viewport_builder = viewport_builder.with_always_on_top();
+    }
+    if native_options.skip_taskbar {
+        viewport_builder = viewport_builder.with_taskbar(false);
+    }
+    if let Some(window_class_name) = &native_options.window_class_name {
+        viewport_builder = viewport_builder.with_app_id(window_class_name.clone());
+    }
+
     // On some Linux systems, a window size larger than the monitor causes crashes,
     // and on Windows the window does not appear at all.
     let clamp_size_to_monitor_size = viewport_builder.clamp_size_to_monitor_size.unwrap_or(true);
 
+    if clamp_size_to_monitor_size
+        && (viewport_builder.min_inner_size.is_some() || viewport_builder.max_inner_size.is_some())
+    {
+        // A minimum size larger than the monitor would make the window impossible to fit.
+        let monitor_size =
+            largest_monitor_point_size(egui_zoom_factor, native_options.dpi_override, event_loop);
+        if let Some(min_inner_size) = viewport_builder.min_inner_size {
+            let clamped = min_inner_size.min(monitor_size);
+            if clamped != min_inner_size {
+                log::warn!(
+                    "min_inner_size {min_inner_size:?} is larger than the largest monitor ({monitor_size:?}); reducing it to {clamped:?}"
+                );
+                viewport_builder = viewport_builder.with_min_inner_size(clamped);
+            }
+        }
+        if let Some(max_inner_size) = viewport_builder.max_inner_size {
+            viewport_builder =
+                viewport_builder.with_max_inner_size(max_inner_size.min(monitor_size));
+        }
+    }
+
     // Always use the default window size / position on iOS. Trying to restore the previous position
     // causes the window to be shown too small.
     #[cfg(not(target_os = "ios"))]
@@ -36,10 +68,16 @@ pub fn viewport_builder(
         if clamp_size_to_monitor_size {
             window_settings.clamp_size_to_sane_values(largest_monitor_point_size(
                 egui_zoom_factor,
+                native_options.dpi_override,
                 event_loop,
             ));
         }
-        window_settings.clamp_position_to_monitors(egui_zoom_factor, event_loop);
+        let on_named_monitor = monitor_name.is_some_and(|monitor_name| {
+            window_settings.clamp_to_named_monitor(egui_zoom_factor, event_loop, monitor_name)
+        });
+        if !on_named_monitor {
+            window_settings.clamp_position_to_monitors(egui_zoom_factor, event_loop);
+        }
 
         viewport_builder = window_settings.initialize_viewport_builder(
             egui_zoom_factor,
@@ -56,37 +94,81 @@ pub fn viewport_builder(
             if let Some(initial_window_size) = viewport_builder.inner_size {
                 let initial_window_size = egui::NumExt::at_most(
                     initial_window_size,
-                    largest_monitor_point_size(egui_zoom_factor, event_loop),
+                    largest_monitor_point_size(
+                        egui_zoom_factor,
+                        native_options.dpi_override,
+                        event_loop,
+                    ),
                 );
                 viewport_builder = viewport_builder.with_inner_size(initial_window_size);
             }
         }
 
+        match native_options.initial_window_state {
+            epi::WindowStartupState::Normal => {}
+            epi::WindowStartupState::Minimized => {
+                // There is no builder option for this, so `EpiIntegration::new` minimizes the window instead.
+            }
+            epi::WindowStartupState::Maximized => {
+                viewport_builder = viewport_builder.with_maximized(true);
+            }
+            epi::WindowStartupState::Fullscreen => {
+                viewport_builder = viewport_builder.with_fullscreen(true);
+            }
+        }
+
         viewport_builder.inner_size
     };
 
     #[cfg(not(target_os = "ios"))]
-    if native_options.centered {
+    if native_options.centered || native_options.center_on_monitor.is_some() {
         profiling::scope!("center");
-        if let Some((monitor, mode)) = event_loop
-            .primary_monitor()
+        let chosen_monitor = native_options.center_on_monitor.and_then(|index| {
+            let monitor = event_loop.available_monitors().nth(index);
+            if monitor.is_none() {
+                log::warn!("NativeOptions::center_on_monitor: there is no monitor {index}");
+            }
+            monitor
+        });
+        if let Some((monitor, mode)) = chosen_monitor
+            .or_else(|| event_loop.primary_monitor())
             .or_else(|| event_loop.available_monitors().next())
             .and_then(|v| v.current_video_mode().map(|e| (v, e)))
         {
-            let monitor_size = mode
-                .size()
-                .to_logical::<f32>(egui_zoom_factor as f64 * monitor.scale_factor());
+            let scale_factor = egui_zoom_factor as f64
+                * monitor_scale_factor(native_options.dpi_override, &monitor);
+            let monitor_size = mode.size().to_logical::<f32>(scale_factor);
+            let monitor_pos = monitor
+                .position()
+                .map_or(winit::dpi::LogicalPosition::new(0.0, 0.0), |pos| {
+                    pos.to_logical::<f32>(scale_factor)
+                });
             let inner_size = inner_size_points.unwrap_or(egui::Vec2 { x: 800.0, y: 600.0 });
             if 0.0 < monitor_size.width && 0.0 < monitor_size.height {
-                let x = (monitor_size.width - inner_size.x) / 2.0;
-                let y = (monitor_size.height - inner_size.y) / 2.0;
+                let x = monitor_pos.x + (monitor_size.width - inner_size.x) / 2.0;
+                let y = monitor_pos.y + (monitor_size.height - inner_size.y) / 2.0;
                 viewport_builder = viewport_builder.with_position([x, y]);
             }
         }
     }
 
-    match std::mem::take(&mut native_options.window_builder) {
-        Some(hook) => hook(viewport_builder),
+    if viewport_builder.icon.is_none() {
+        // winit only takes a single icon:
+        const WINDOW_ICON_SIZE: u32 = 64;
+        if let Some(icon) = crate::icon_data::best_fit(
+            native_options.icon_sizes.iter().map(|icon| &**icon),
+            WINDOW_ICON_SIZE,
+        ) {
+            viewport_builder = viewport_builder.with_icon(icon);
+        }
+    }
+
+    if let Some(hook) = std::mem::take(&mut native_options.window_builder) {
+        viewport_builder = hook(viewport_builder);
+    }
+
+    match &native_options.viewport_builder_hook {
+        Some(hook) => hook(ViewportId::ROOT, viewport_builder),
         None => viewport_builder,
     }
 }
@@ -104,6 +186,7 @@ pub fn apply_window_settings(
 #[cfg(not(target_os = "ios"))]
 fn largest_monitor_point_size(
     egui_zoom_factor: f32,
+    dpi_override: Option<f32>,
     event_loop: &dyn ActiveEventLoop,
 ) -> egui::Vec2 {
     profiling::function_scope!();
@@ -118,9 +201,9 @@ fn largest_monitor_point_size(
         let Some(mode) = monitor.current_video_mode() else {
             continue;
         };
-        let size = mode
-            .size()
-            .to_logical::<f32>(egui_zoom_factor as f64 * monitor.scale_factor());
+        let size = mode.size().to_logical::<f32>(
+            egui_zoom_factor as f64 * monitor_scale_factor(dpi_override, &monitor),
+        );
         let size = egui::vec2(size.width, size.height);
         max_size = max_size.max(size);
     }
@@ -132,13 +215,24 @@ fn largest_monitor_point_size(
     }
 }
 
+/// The scale factor of the monitor, unless overridden by [`epi::NativeOptions::dpi_override`].
+#[cfg(not(target_os = "ios"))]
+fn monitor_scale_factor(dpi_override: Option<f32>, monitor: &winit::monitor::MonitorHandle) -> f64 {
+    dpi_override.map_or_else(|| monitor.scale_factor(), f64::from)
+}
+
 // ----------------------------------------------------------------------------
 
 /// For loading/saving app state and/or egui memory to disk.
 pub fn create_storage(_app_name: &str) -> Option<Box<dyn epi::Storage>> {
     #[cfg(feature = "persistence")]
-    if let Some(storage) = super::file_storage::FileStorage::from_app_id(_app_name) {
-        return Some(Box::new(storage));
+    {
+        if let Some(storage) = super::file_storage::FileStorage::from_app_id(_app_name) {
+            return Some(Box::new(storage));
+        }
+        log::warn!(
+            "The 'persistence' feature is enabled, but no storage is available for {_app_name:?}. App state will not be saved."
+        );
     }
     None
 }
@@ -163,23 +257,88 @@ pub struct EpiIntegration {
     last_auto_save: Instant,
     pub beginning: Instant,
     is_first_frame: bool,
+
+    /// Rolling window of recent frame times, for [`epi::FrameStats::fps_smoothed`].
+    frame_time_history: egui::util::History<f32>,
     pub egui_ctx: egui::Context,
     pending_full_output: egui::FullOutput,
 
     /// When set, it is time to close the native window.
     close: bool,
 
+    /// Set when the app itself sent [`egui::ViewportCommand::Close`] for the root viewport,
+    /// so we don't ask [`epi::App::on_exit_requested`] about its own decision.
+    close_requested_by_app: bool,
+
+    /// See [`epi::NativeOptions::viewport_close_behavior`].
+    close_behavior: epi::CloseBehavior,
+
+    /// See [`epi::NativeOptions::follow_system_theme`].
+    follow_system_theme: bool,
+
     can_drag_window: bool,
+
+    /// Files dropped since the last call to [`epi::App::on_drop_files`].
+    dropped_files: Vec<PathBuf>,
+
+    /// The root window that [`epi::Frame::current_monitor`] was last read for.
+    ///
+    /// Reset when the window moves or changes size or scale,
+    /// since asking for the monitor and its video mode is not free.
+    monitor_window_id: Option<winit::window::WindowId>,
+
     #[cfg(feature = "persistence")]
     persist_window: bool,
+    #[cfg(feature = "persistence")]
+    persist_window_only: bool,
+
+    /// The [`WindowSettings`] we last wrote to storage, so we don't write them again unchanged.
+    #[cfg(feature = "persistence")]
+    last_saved_window_settings: Option<WindowSettings>,
+
+    /// The monitor name we last wrote to storage, see [`load_window_monitor_name`].
+    #[cfg(feature = "persistence")]
+    last_saved_monitor_name: Option<String>,
     app_icon_setter: super::app_icon::AppTitleIconSetter,
 }
 
+/// For [`epi::Frame::current_monitor`].
+fn current_monitor_info(window: &dyn winit::window::Window) -> Option<epi::MonitorInfo> {
+    profiling::function_scope!();
+    let monitor = window.current_monitor()?;
+    let video_mode = monitor.current_video_mode();
+    Some(epi::MonitorInfo {
+        size_px: video_mode
+            .as_ref()
+            .map_or([0, 0], |mode| [mode.size().width, mode.size().height]),
+        scale_factor: monitor.scale_factor(),
+        name: monitor.name().map(|name| name.to_string()),
+        refresh_rate_hz: video_mode
+            .and_then(|mode| mode.refresh_rate_millihertz())
+            .map(|millihertz| millihertz.get() as f32 / 1000.0),
+    })
+}
+
+/// See [`epi::NativeOptions::blur_behind_window`].
+fn set_blur_behind_window(window: &dyn winit::window::Window) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::{BackdropType, WindowExtWindows as _};
+        window.set_system_backdrop(BackdropType::TransientWindow);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    window.set_blur(true);
+}
+
 impl EpiIntegration {
+    /// `window` is `None` when running headless, see [`crate::run_headless`].
     #[expect(clippy::too_many_arguments)]
     pub fn new(
         egui_ctx: egui::Context,
-        window: &dyn winit::window::Window,
+        window: Option<&dyn winit::window::Window>,
         app_name: &str,
         native_options: &crate::NativeOptions,
         storage: Option<Box<dyn epi::Storage>>,
@@ -189,17 +348,51 @@ impl EpiIntegration {
         >,
         #[cfg(feature = "wgpu")] wgpu_render_state: Option<egui_wgpu::RenderState>,
     ) -> Self {
+        if native_options.initial_window_state == epi::WindowStartupState::Minimized
+            && load_window_settings(storage.as_deref()).is_none()
+        {
+            egui_ctx.send_viewport_cmd_to(ViewportId::ROOT, egui::ViewportCommand::Minimized(true));
+        }
+
+        if native_options.blur_behind_window {
+            if let Some(window) = window {
+                set_blur_behind_window(window);
+            }
+        }
+
         let frame = epi::Frame {
-            info: epi::IntegrationInfo { cpu_usage: None },
+            info: epi::IntegrationInfo {
+                cpu_usage: None,
+                cpu_usage_history: Default::default(),
+                update_time: None,
+                tessellation_time: None,
+                paint_time: None,
+            },
+            frame_stats: Default::default(),
+            monitor: None,
+            screen_size_in_pixels: None,
             storage,
+            auto_save_interval: None,
+            title: None,
             #[cfg(feature = "glow")]
             gl,
             #[cfg(feature = "glow")]
             glow_register_native_texture,
             #[cfg(feature = "wgpu")]
             wgpu_render_state,
-            raw_display_handle: window.display_handle().map(|h| h.as_raw()),
-            raw_window_handle: window.window_handle().map(|h| h.as_raw()),
+            raw_display_handle: window.map_or(Err(HandleError::NotSupported), |w| {
+                w.display_handle().map(|h| h.as_raw())
+            }),
+            raw_window_handle: window.map_or(Err(HandleError::NotSupported), |w| {
+                w.window_handle().map(|h| h.as_raw())
+            }),
+            screenshot_requested: Default::default(),
+            screenshot: Default::default(),
+            screenshot_to_app: Default::default(),
+            user_attention: Default::default(),
+            show_window: Default::default(),
+            exit_code: 0,
+            user_event_sender: None,
         };
 
         let icon = native_options
@@ -215,6 +408,7 @@ impl EpiIntegration {
                 .clone()
                 .unwrap_or_else(|| app_name.to_owned()),
             Some(icon),
+            native_options.icon_sizes.clone(),
         );
 
         Self {
@@ -223,12 +417,50 @@ impl EpiIntegration {
             egui_ctx,
             pending_full_output: Default::default(),
             close: false,
+            close_requested_by_app: false,
+            close_behavior: native_options.viewport_close_behavior,
+            follow_system_theme: native_options.follow_system_theme,
             can_drag_window: false,
+            dropped_files: Vec::new(),
+            monitor_window_id: None,
             #[cfg(feature = "persistence")]
             persist_window: native_options.persist_window,
+            #[cfg(feature = "persistence")]
+            persist_window_only: native_options.persist_window_only,
+            #[cfg(feature = "persistence")]
+            last_saved_window_settings: None,
+            #[cfg(feature = "persistence")]
+            last_saved_monitor_name: None,
             app_icon_setter,
             beginning: Instant::now(),
             is_first_frame: true,
+            frame_time_history: epi::FrameStats::new_history(),
+        }
+    }
+
+    /// Call [`epi::App::on_storage_load_error`] for any of eframe's own state that failed to load.
+    ///
+    /// Call once, right after creating the app.
+    #[allow(clippy::unused_self, clippy::allow_attributes)]
+    pub fn report_storage_load_errors(&self, _app: &mut dyn epi::App) {
+        #[cfg(feature = "persistence")]
+        if let Some(storage) = self.frame.storage() {
+            profiling::function_scope!();
+
+            if let Err(err) = epi::try_get_value::<WindowSettings>(storage, STORAGE_WINDOW_KEY) {
+                _app.on_storage_load_error(STORAGE_WINDOW_KEY, &err);
+            }
+            if let Err(err) = epi::try_get_value::<egui::Memory>(storage, STORAGE_EGUI_MEMORY_KEY) {
+                _app.on_storage_load_error(STORAGE_EGUI_MEMORY_KEY, &err);
+            }
+        }
+    }
+
+    /// Run [`epi::App::migrate_storage`] if needed. Call once, right after creating the app.
+    pub fn migrate_storage(&mut self, app: &mut dyn epi::App) {
+        if let Some(storage) = self.frame.storage_mut() {
+            profiling::function_scope!();
+            epi::migrate_storage(app, storage);
         }
     }
 
@@ -239,6 +471,8 @@ impl EpiIntegration {
 
     pub fn on_window_event(
         &mut self,
+        app: &mut dyn epi::App,
+        viewport_id: ViewportId,
         window: &dyn winit::window::Window,
         egui_winit: &mut egui_winit::State,
         event: &winit::event::WindowEvent,
@@ -256,11 +490,57 @@ impl EpiIntegration {
             self.can_drag_window |= button.mouse_button() == MouseButton::Left;
         }
 
+        if viewport_id == ViewportId::ROOT
+            && matches!(
+                event,
+                WindowEvent::Moved(_)
+                    | WindowEvent::ScaleFactorChanged { .. }
+                    | WindowEvent::SurfaceResized(_)
+            )
+        {
+            self.monitor_window_id = None;
+        }
+
+        if let WindowEvent::DragDropped { paths, .. } = event {
+            self.dropped_files.extend(paths.iter().cloned());
+        }
+
+        if let WindowEvent::Focused(focused) = event {
+            if viewport_id == ViewportId::ROOT {
+                // Let the app react before egui does:
+                app.on_focus_changed(&self.egui_ctx, *focused);
+            }
+        }
+
+        if let WindowEvent::ThemeChanged(theme) = event {
+            if viewport_id == ViewportId::ROOT {
+                let dark = *theme == winit::window::Theme::Dark;
+                if self.follow_system_theme {
+                    self.egui_ctx.set_visuals(if dark {
+                        egui::Visuals::dark()
+                    } else {
+                        egui::Visuals::light()
+                    });
+                }
+                app.on_theme_changed(&self.egui_ctx, dark);
+            }
+        }
+
         egui_winit.on_window_event(window, event)
     }
 
-    pub fn pre_update(&mut self) {
+    pub fn pre_update(&mut self, viewport_id: ViewportId, window: &dyn winit::window::Window) {
         self.app_icon_setter.update();
+
+        if viewport_id == ViewportId::ROOT {
+            let size = window.surface_size();
+            self.frame.screen_size_in_pixels = Some([size.width, size.height]);
+
+            if self.monitor_window_id != Some(window.id()) {
+                self.monitor_window_id = Some(window.id());
+                self.frame.monitor = current_monitor_info(window);
+            }
+        }
     }
 
     /// Run user code - this can create immediate viewports, so hold no locks over this!
@@ -273,49 +553,166 @@ impl EpiIntegration {
         mut raw_input: egui::RawInput,
     ) -> egui::FullOutput {
         raw_input.time = Some(self.beginning.elapsed().as_secs_f64());
+        self.update_with_input_time(app, viewport_ui_cb, raw_input)
+    }
+
+    /// Like [`Self::update`], but keeps the [`egui::RawInput::time`] of `raw_input`, if set.
+    pub fn update_with_input_time(
+        &mut self,
+        app: &mut dyn epi::App,
+        viewport_ui_cb: Option<&DeferredViewportUiCallback>,
+        mut raw_input: egui::RawInput,
+    ) -> egui::FullOutput {
+        raw_input
+            .time
+            .get_or_insert_with(|| self.beginning.elapsed().as_secs_f64());
 
         let close_requested = raw_input.viewport().close_requested();
 
+        // Screenshots requested with `Frame::screenshot` are for the app, not for egui:
+        raw_input.events.retain(|event| {
+            if let egui::Event::Screenshot {
+                user_data, image, ..
+            } = event
+            {
+                if is_frame_screenshot(user_data) {
+                    self.frame.screenshot.set(Some((**image).clone()));
+                    return false;
+                }
+            }
+            true
+        });
+
+        if viewport_ui_cb.is_none() {
+            self.poll_screenshot_for_app(app);
+        }
+
+        if viewport_ui_cb.is_none() {
+            app.pre_frame(&self.egui_ctx, &mut self.frame);
+        }
+
         app.raw_input_hook(&self.egui_ctx, &mut raw_input);
 
-        let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
+        let mut full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
             if let Some(viewport_ui_cb) = viewport_ui_cb {
                 // Child viewport
                 profiling::scope!("viewport_callback");
                 viewport_ui_cb(egui_ctx);
             } else {
                 profiling::scope!("App::update");
+                let update_start = Instant::now();
                 app.update(egui_ctx, &mut self.frame);
+                self.frame.info.update_time = Some(update_start.elapsed().as_secs_f32());
+
+                if let Some(title) = self.frame.title.take() {
+                    self.app_icon_setter.set_title(title.clone());
+                    egui_ctx.send_viewport_cmd(egui::ViewportCommand::Title(title));
+                }
+                if let Some(kind) = self.frame.user_attention.take() {
+                    egui_ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(kind));
+                }
+                if self.frame.show_window.take() {
+                    egui_ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                    egui_ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                }
+
+                if !self.dropped_files.is_empty() {
+                    let paths = std::mem::take(&mut self.dropped_files);
+                    app.on_drop_files(egui_ctx, &paths);
+                }
             }
         });
 
+        app.raw_output_hook(&self.egui_ctx, &mut full_output);
+
         let is_root_viewport = viewport_ui_cb.is_none();
+        if is_root_viewport {
+            // Schedule the capture of this frame, if the app just called `request_screenshot`:
+            self.poll_screenshot_for_app(app);
+        }
         if is_root_viewport && close_requested {
+            let requested_by_app = std::mem::take(&mut self.close_requested_by_app);
             let canceled = full_output.viewport_output[&ViewportId::ROOT]
                 .commands
                 .contains(&egui::ViewportCommand::CancelClose);
             if canceled {
                 log::debug!("Closing of root viewport canceled with ViewportCommand::CancelClose");
+            } else if !requested_by_app && self.close_behavior == epi::CloseBehavior::HideToTray {
+                log::debug!(
+                    "Hiding root viewport instead of closing it (CloseBehavior::HideToTray)"
+                );
+                if let Some(output) = full_output.viewport_output.get_mut(&ViewportId::ROOT) {
+                    output.commands.push(egui::ViewportCommand::Visible(false));
+                }
+            } else if !requested_by_app
+                && app.on_exit_requested(&self.egui_ctx) == epi::ExitAction::Cancel
+            {
+                log::debug!("Closing of root viewport canceled by App::on_exit_requested");
             } else {
                 log::debug!("Closing root viewport (ViewportCommand::CancelClose was not sent)");
                 self.close = true;
             }
         }
 
+        if is_root_viewport {
+            self.close_requested_by_app |= full_output
+                .viewport_output
+                .get(&ViewportId::ROOT)
+                .is_some_and(|output| output.commands.contains(&egui::ViewportCommand::Close));
+        }
+
         self.pending_full_output.append(full_output);
         std::mem::take(&mut self.pending_full_output)
     }
 
+    /// [`epi::Frame::request_screenshot`] is built on [`epi::Frame::screenshot`],
+    /// which we keep calling on behalf of the app until the image arrives.
+    fn poll_screenshot_for_app(&mut self, app: &mut dyn epi::App) {
+        if self.frame.screenshot_to_app.get() {
+            if let Some(image) = self.frame.screenshot() {
+                self.frame.screenshot_to_app.set(false);
+                app.on_screenshot(image);
+            }
+        }
+    }
+
     pub fn report_frame_time(&mut self, seconds: f32) {
-        self.frame.info.cpu_usage = Some(seconds);
+        self.frame.info.report_cpu_usage(seconds);
+        self.frame.frame_stats.report(
+            &mut self.frame_time_history,
+            self.beginning.elapsed().as_secs_f64(),
+            seconds,
+        );
     }
 
-    pub fn post_rendering(&mut self, window: &dyn winit::window::Window) {
+    pub fn post_rendering(
+        &mut self,
+        app: &mut dyn epi::App,
+        viewport_id: ViewportId,
+        window: &dyn winit::window::Window,
+    ) {
         profiling::function_scope!();
-        if std::mem::take(&mut self.is_first_frame) {
+        if self.is_first_frame {
             // We keep hidden until we've painted something. See https://github.com/emilk/egui/pull/2279
             window.set_visible(true);
         }
+        self.post_rendering_with_size(app, viewport_id, window.surface_size().into());
+    }
+
+    /// Like [`Self::post_rendering`], for when there is no window, e.g. when running headless.
+    pub fn post_rendering_with_size(
+        &mut self,
+        app: &mut dyn epi::App,
+        viewport_id: ViewportId,
+        size_in_pixels: [u32; 2],
+    ) {
+        if std::mem::take(&mut self.is_first_frame) {
+            app.on_first_frame(&self.egui_ctx, &mut self.frame);
+        }
+
+        if viewport_id == ViewportId::ROOT {
+            app.post_rendering(size_in_pixels, &mut self.frame);
+        }
     }
 
     // ------------------------------------------------------------------------
@@ -327,7 +724,11 @@ impl EpiIntegration {
         window: Option<&dyn winit::window::Window>,
     ) {
         let now = Instant::now();
-        if now - self.last_auto_save > app.auto_save_interval() {
+        let auto_save_interval = self
+            .frame
+            .auto_save_interval
+            .unwrap_or_else(|| app.auto_save_interval());
+        if epi::is_auto_save_due(now - self.last_auto_save, auto_save_interval) {
             self.save(app, window);
             self.last_auto_save = now;
         }
@@ -342,21 +743,40 @@ impl EpiIntegration {
             if let Some(window) = _window {
                 if self.persist_window {
                     profiling::scope!("native_window");
-                    epi::set_value(
-                        storage,
-                        STORAGE_WINDOW_KEY,
-                        &WindowSettings::from_window(self.egui_ctx.zoom_factor(), window),
-                    );
+                    let window_settings =
+                        WindowSettings::from_window(self.egui_ctx.zoom_factor(), window);
+                    if self.last_saved_window_settings != Some(window_settings) {
+                        epi::set_value(storage, STORAGE_WINDOW_KEY, &window_settings);
+                        self.last_saved_window_settings = Some(window_settings);
+                    }
+
+                    // If the monitor is unknown now, we keep the one we knew about.
+                    if let Some(monitor_name) = WindowSettings::monitor_name(window) {
+                        if self.last_saved_monitor_name.as_ref() != Some(&monitor_name) {
+                            storage.set_string(STORAGE_WINDOW_MONITOR_KEY, monitor_name.clone());
+                            self.last_saved_monitor_name = Some(monitor_name);
+                        }
+                    }
                 }
             }
-            if _app.persist_egui_memory() {
-                profiling::scope!("egui_memory");
-                self.egui_ctx
-                    .memory(|mem| epi::set_value(storage, STORAGE_EGUI_MEMORY_KEY, mem));
-            }
-            {
-                profiling::scope!("App::save");
-                _app.save(storage);
+            if !self.persist_window_only {
+                let memory_flags = _app.persist_egui_memory_flags();
+                if !memory_flags.is_empty() {
+                    profiling::scope!("egui_memory");
+                    self.egui_ctx.memory(|mem| {
+                        if memory_flags.is_all() {
+                            epi::set_value(storage, STORAGE_EGUI_MEMORY_KEY, mem);
+                        } else {
+                            let mut mem = mem.clone();
+                            memory_flags.filter_memory(&mut mem);
+                            epi::set_value(storage, STORAGE_EGUI_MEMORY_KEY, &mem);
+                        }
+                    });
+                }
+                {
+                    profiling::scope!("App::save");
+                    _app.save(storage);
+                }
             }
 
             profiling::scope!("Storage::flush");
@@ -365,6 +785,21 @@ impl EpiIntegration {
     }
 }
 
+/// Marks a screenshot as requested by [`epi::Frame::screenshot`].
+struct FrameScreenshot;
+
+/// The [`egui::UserData`] to attach to a capture requested by [`epi::Frame::screenshot`].
+pub fn frame_screenshot_user_data() -> egui::UserData {
+    egui::UserData::new(FrameScreenshot)
+}
+
+fn is_frame_screenshot(user_data: &egui::UserData) -> bool {
+    user_data
+        .data
+        .as_ref()
+        .is_some_and(|data| data.is::<FrameScreenshot>())
+}
+
 fn load_default_egui_icon() -> egui::IconData {
     profiling::function_scope!();
     crate::icon_data::from_png_bytes(&include_bytes!("../../data/icon.png")[..]).unwrap()
@@ -376,6 +811,9 @@ const STORAGE_EGUI_MEMORY_KEY: &str = "egui";
 #[cfg(feature = "persistence")]
 const STORAGE_WINDOW_KEY: &str = "window";
 
+#[cfg(feature = "persistence")]
+const STORAGE_WINDOW_MONITOR_KEY: &str = "window_monitor";
+
 pub fn load_window_settings(_storage: Option<&dyn epi::Storage>) -> Option<WindowSettings> {
     profiling::function_scope!();
     #[cfg(feature = "persistence")]
@@ -386,6 +824,18 @@ pub fn load_window_settings(_storage: Option<&dyn epi::Storage>) -> Option<Windo
     None
 }
 
+/// The name of the monitor the window was last on.
+///
+/// Stored separately from the [`WindowSettings`], see [`WindowSettings::clamp_to_named_monitor`].
+pub fn load_window_monitor_name(_storage: Option<&dyn epi::Storage>) -> Option<String> {
+    #[cfg(feature = "persistence")]
+    {
+        _storage?.get_string(STORAGE_WINDOW_MONITOR_KEY)
+    }
+    #[cfg(not(feature = "persistence"))]
+    None
+}
+
 pub fn load_egui_memory(_storage: Option<&dyn epi::Storage>) -> Option<egui::Memory> {
     profiling::function_scope!();
     #[cfg(feature = "persistence")]
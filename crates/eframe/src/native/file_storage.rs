@@ -4,96 +4,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-/// The folder where `eframe` will store its state.
-///
-/// The given `app_id` is either the
-/// [`egui::ViewportBuilder::app_id`] of [`crate::NativeOptions::viewport`]
-/// or the title argument to [`crate::run_native`].
-///
-/// On native, the path is:
-/// * Linux:   `/home/UserName/.local/share/APP_ID`
-/// * macOS:   `/Users/UserName/Library/Application Support/APP_ID`
-/// * Windows: `C:\Users\UserName\AppData\Roaming\APP_ID\data`
-pub fn storage_dir(app_id: &str) -> Option<PathBuf> {
-    use egui::os::OperatingSystem as OS;
-    use std::env::var_os;
-    match OS::from_target_os() {
-        OS::Nix => var_os("XDG_DATA_HOME")
-            .map(PathBuf::from)
-            .filter(|p| p.is_absolute())
-            .or_else(|| home::home_dir().map(|p| p.join(".local").join("share")))
-            .map(|p| {
-                p.join(
-                    app_id
-                        .to_lowercase()
-                        .replace(|c: char| c.is_ascii_whitespace(), ""),
-                )
-            }),
-        OS::Mac => home::home_dir().map(|p| {
-            p.join("Library")
-                .join("Application Support")
-                .join(app_id.replace(|c: char| c.is_ascii_whitespace(), "-"))
-        }),
-        OS::Windows => roaming_appdata().map(|p| p.join(app_id).join("data")),
-        OS::Unknown | OS::Android | OS::IOS => None,
-    }
-}
-
-// Adapted from
-// https://github.com/rust-lang/cargo/blob/6e11c77384989726bb4f412a0e23b59c27222c34/crates/home/src/windows.rs#L19-L37
-#[cfg(all(windows, not(target_vendor = "uwp")))]
-#[expect(unsafe_code)]
-fn roaming_appdata() -> Option<PathBuf> {
-    use std::ffi::OsString;
-    use std::os::windows::ffi::OsStringExt as _;
-    use std::ptr;
-    use std::slice;
-
-    use windows_sys::Win32::Foundation::S_OK;
-    use windows_sys::Win32::System::Com::CoTaskMemFree;
-    use windows_sys::Win32::UI::Shell::{
-        FOLDERID_RoamingAppData, KF_FLAG_DONT_VERIFY, SHGetKnownFolderPath,
-    };
-
-    unsafe extern "C" {
-        fn wcslen(buf: *const u16) -> usize;
-    }
-    let mut path_raw = ptr::null_mut();
-
-    // SAFETY: SHGetKnownFolderPath allocates for us, we don't pass any pointers to it.
-    // See https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath
-    let result = unsafe {
-        SHGetKnownFolderPath(
-            &FOLDERID_RoamingAppData,
-            KF_FLAG_DONT_VERIFY as u32,
-            std::ptr::null_mut(),
-            &mut path_raw,
-        )
-    };
-
-    let path = if result == S_OK {
-        // SAFETY: SHGetKnownFolderPath indicated success and is supposed to allocate a null-terminated string for us.
-        let path_slice = unsafe { slice::from_raw_parts(path_raw, wcslen(path_raw)) };
-        Some(PathBuf::from(OsString::from_wide(path_slice)))
-    } else {
-        None
-    };
-
-    // SAFETY:
-    // This memory got allocated by SHGetKnownFolderPath, we didn't touch anything in the process.
-    // A null ptr is a no-op for `CoTaskMemFree`, so in case this failed we're still good.
-    // https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemfree
-    unsafe { CoTaskMemFree(path_raw.cast()) };
-
-    path
-}
-
-#[cfg(any(not(windows), target_vendor = "uwp"))]
-fn roaming_appdata() -> Option<PathBuf> {
-    None
-}
-
-// ----------------------------------------------------------------------------
+use super::storage_dir::{STORAGE_FILE_NAME, storage_dir};
 
 /// A key-value store backed by a [RON](https://github.com/ron-rs/ron) file on disk.
 /// Used to restore egui state, glow window position/size and app state.
@@ -139,10 +50,13 @@ impl FileStorage {
                 );
                 None
             } else {
-                Some(Self::from_ron_filepath(data_dir.join("app.ron")))
+                Some(Self::from_ron_filepath(data_dir.join(STORAGE_FILE_NAME)))
             }
         } else {
-            log::warn!("Saving disabled: Failed to find path to data_dir.");
+            log::warn!(
+                "Saving disabled: Failed to find path to data_dir (no home directory, or unsupported OS {:?}).",
+                egui::os::OperatingSystem::from_target_os()
+            );
             None
         }
     }
@@ -160,6 +74,44 @@ impl crate::Storage for FileStorage {
         }
     }
 
+    fn list_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.kv.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    fn delete_key(&mut self, key: &str) {
+        if self.kv.remove(key).is_some() {
+            self.dirty = true;
+        }
+    }
+
+    fn clear(&mut self) {
+        if !self.kv.is_empty() {
+            self.kv.clear();
+            self.dirty = true;
+        }
+    }
+
+    /// The stored values as a pretty-printed RON map, just like the file on disk.
+    fn export_to_string(&self) -> String {
+        let kv: std::collections::BTreeMap<&String, &String> = self.kv.iter().collect();
+        ron::ser::to_string_pretty(&kv, Default::default()).unwrap_or_else(|err| {
+            log::warn!("Failed to serialize app state: {err}");
+            String::new()
+        })
+    }
+
+    /// Reads a RON map of strings, as produced by [`Self::export_to_string`].
+    fn import_from_str(&mut self, s: &str) -> Result<(), crate::StorageError> {
+        let kv: HashMap<String, String> =
+            ron::from_str(s).map_err(|err| crate::StorageError(err.to_string()))?;
+        for (key, value) in kv {
+            self.set_string(&key, value);
+        }
+        Ok(())
+    }
+
     fn flush(&mut self) {
         if self.dirty {
             profiling::scope!("FileStorage::flush");
@@ -201,7 +153,13 @@ fn save_to_disk(file_path: &PathBuf, kv: &HashMap<String, String>) {
         }
     }
 
-    match std::fs::File::create(file_path) {
+    // Write to a temporary sibling file first, and then rename it over the target.
+    // That way a crash mid-write leaves either the old or the new file intact, never a truncated one.
+    let mut tmp_file_name = file_path.file_name().unwrap_or_default().to_owned();
+    tmp_file_name.push(".tmp");
+    let tmp_file_path = file_path.with_file_name(tmp_file_name);
+
+    match std::fs::File::create(&tmp_file_path) {
         Ok(file) => {
             let mut writer = std::io::BufWriter::new(file);
             let config = Default::default();
@@ -210,14 +168,24 @@ fn save_to_disk(file_path: &PathBuf, kv: &HashMap<String, String>) {
             if let Err(err) = ron::Options::default()
                 .to_io_writer_pretty(&mut writer, &kv, config)
                 .and_then(|_| writer.flush().map_err(|err| err.into()))
+                .and_then(|_| writer.get_ref().sync_all().map_err(|err| err.into()))
             {
                 log::warn!("Failed to serialize app state: {}", err);
+                drop(writer);
+                std::fs::remove_file(&tmp_file_path).ok();
             } else {
-                log::trace!("Persisted to {:?}", file_path);
+                drop(writer);
+                if let Err(err) = std::fs::rename(&tmp_file_path, file_path) {
+                    log::warn!(
+                        "Failed to move {tmp_file_path:?} to {file_path:?}: {err}. The app state is kept in {tmp_file_path:?}."
+                    );
+                } else {
+                    log::trace!("Persisted to {:?}", file_path);
+                }
             }
         }
         Err(err) => {
-            log::warn!("Failed to create file {file_path:?}: {err}");
+            log::warn!("Failed to create file {tmp_file_path:?}: {err}");
         }
     }
 }
@@ -251,18 +219,41 @@ where
 mod tests {
     use super::*;
 
-    fn directories_storage_dir(app_id: &str) -> Option<PathBuf> {
-        directories::ProjectDirs::from("", "", app_id)
-            .map(|proj_dirs| proj_dirs.data_dir().to_path_buf())
+    #[test]
+    fn list_delete_and_clear_keys() {
+        use crate::Storage as _;
+
+        let mut storage = FileStorage::from_ron_filepath("this/file/does/not/exist.ron");
+        storage.set_string("b", "2".to_owned());
+        storage.set_string("a", "1".to_owned());
+        assert_eq!(storage.list_keys(), vec!["a".to_owned(), "b".to_owned()]);
+
+        storage.dirty = false;
+        storage.delete_key("a");
+        assert!(storage.dirty);
+        assert_eq!(storage.get_string("a"), None);
+        assert_eq!(storage.list_keys(), vec!["b".to_owned()]);
+
+        storage.dirty = false;
+        storage.clear();
+        assert!(storage.dirty);
+        assert!(storage.list_keys().is_empty());
     }
 
     #[test]
-    fn storage_path_matches_directories() {
-        use super::storage_dir;
-        for app_id in [
-            "MyApp", "My App", "my_app", "my-app", "My.App", "my/app", "my:app", r"my\app",
-        ] {
-            assert_eq!(directories_storage_dir(app_id), storage_dir(app_id));
-        }
+    fn export_import_roundtrip() {
+        use crate::Storage as _;
+
+        let mut storage = FileStorage::from_ron_filepath("this/file/does/not/exist.ron");
+        storage.set_string("app", "(value: \"a=b\")".to_owned());
+        storage.set_string("window", "(x: 1)".to_owned());
+        let exported = storage.export_to_string();
+
+        let mut other = FileStorage::from_ron_filepath("this/file/does/not/exist.ron");
+        other.import_from_str(&exported).unwrap();
+        assert_eq!(other.kv, storage.kv);
+
+        assert!(other.import_from_str("not ron").is_err());
+        assert_eq!(other.kv, storage.kv);
     }
 }
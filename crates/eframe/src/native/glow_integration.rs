@@ -38,13 +38,13 @@ use egui::{
 use egui_winit::accesskit_winit;
 
 use crate::{
-    App, AppCreator, CreationContext, NativeOptions, Result, Storage,
-    native::epi_integration::EpiIntegration,
+    App, AppCreator, CreationContext, NativeOptions, Renderer, Result, Storage,
+    ViewportBuilderHook, native::epi_integration::EpiIntegration,
 };
 
 use super::{
     epi_integration, event_loop_context,
-    winit_integration::{EventResult, UserEvent, WinitApp, create_egui_context},
+    winit_integration::{self, EventResult, UserEvent, WinitApp, create_egui_context},
 };
 
 // ----------------------------------------------------------------------------
@@ -62,6 +62,12 @@ pub struct GlowWinitApp<'app> {
     // re-initializing the `GlowWinitRunning` state on Android if the application
     // suspends and resumes.
     app_creator: Option<AppCreator<'app>>,
+
+    /// An existing context to use instead of creating a new one, taken on first resume.
+    egui_ctx: Option<egui::Context>,
+
+    #[cfg(feature = "tray_icon")]
+    _tray: Option<super::tray::TrayHandle>,
 }
 
 /// State that is initialized when the application is first starts running via
@@ -107,6 +113,9 @@ struct GlutinWindowContext {
     window_from_viewport: ViewportIdMap<WindowId>,
 
     focused_viewport: Option<ViewportId>,
+
+    /// See [`NativeOptions::viewport_builder_hook`].
+    viewport_builder_hook: Option<ViewportBuilderHook>,
 }
 
 struct Viewport {
@@ -147,9 +156,46 @@ impl<'app> GlowWinitApp<'app> {
             native_options,
             running: None,
             app_creator: Some(app_creator),
+            egui_ctx: None,
+            #[cfg(feature = "tray_icon")]
+            _tray: None,
         }
     }
 
+    /// Use this context instead of creating a new one.
+    ///
+    /// See [`crate::create_native_with_context`].
+    pub fn with_egui_ctx(mut self, egui_ctx: Option<egui::Context>) -> Self {
+        self.egui_ctx = egui_ctx;
+        self
+    }
+
+    /// See [`crate::NativeOptions::save_on_os_signal`].
+    #[cfg(feature = "os_signals")]
+    fn install_os_signal_handler(&self) {
+        let event_loop_proxy = self.repaint_proxy.clone();
+        let user_events_sender = self.user_events_sender.clone();
+        winit_integration::install_os_signal_handler(move || {
+            if user_events_sender.send(UserEvent::OsSignal).is_err() {
+                // The event loop is already gone, so there is nothing left to save.
+                std::process::exit(1);
+            }
+            event_loop_proxy.lock().wake_up();
+        });
+    }
+
+    #[cfg(feature = "tray_icon")]
+    fn create_tray(&mut self) {
+        let event_loop_proxy = self.repaint_proxy.clone();
+        let user_events_sender = self.user_events_sender.clone();
+        self._tray = super::tray::create_tray(&self.native_options, move |id| {
+            user_events_sender
+                .send(UserEvent::TrayMenuActivated(id))
+                .ok();
+            event_loop_proxy.lock().wake_up();
+        });
+    }
+
     #[expect(unsafe_code)]
     fn create_glutin_windowed_context(
         egui_ctx: &egui::Context,
@@ -165,6 +211,7 @@ impl<'app> GlowWinitApp<'app> {
             event_loop,
             native_options,
             window_settings,
+            epi_integration::load_window_monitor_name(storage).as_deref(),
         )
         .with_visible(false); // Start hidden until we render the first frame to fix white flash on startup (https://github.com/emilk/egui/pull/3631)
 
@@ -219,7 +266,7 @@ impl<'app> GlowWinitApp<'app> {
             )
         };
 
-        let egui_ctx = create_egui_context(storage.as_deref());
+        let egui_ctx = create_egui_context(storage.as_deref(), self.egui_ctx.take());
 
         let (mut glutin, painter) = Self::create_glutin_windowed_context(
             &egui_ctx,
@@ -239,9 +286,9 @@ impl<'app> GlowWinitApp<'app> {
 
         let painter = Rc::new(RefCell::new(painter));
 
-        let integration = EpiIntegration::new(
+        let mut integration = EpiIntegration::new(
             egui_ctx,
-            glutin.window(ViewportId::ROOT).as_ref(),
+            Some(glutin.window(ViewportId::ROOT).as_ref()),
             &self.app_name,
             &self.native_options,
             storage,
@@ -254,6 +301,8 @@ impl<'app> GlowWinitApp<'app> {
             None,
         );
 
+        integration.frame.user_event_sender = Some(self.user_event_sender());
+
         {
             let event_loop_proxy = self.repaint_proxy.clone();
             let user_events_sender = self.user_events_sender.clone();
@@ -303,7 +352,7 @@ impl<'app> GlowWinitApp<'app> {
         let app_creator = std::mem::take(&mut self.app_creator)
             .expect("Single-use AppCreator has unexpectedly already been taken");
 
-        let app: Box<dyn 'app + App> = {
+        let mut app: Box<dyn 'app + App> = {
             // Use latest raw_window_handle for eframe compatibility
             use raw_window_handle::{HasDisplayHandle as _, HasWindowHandle as _};
 
@@ -313,6 +362,7 @@ impl<'app> GlowWinitApp<'app> {
                 egui_ctx: integration.egui_ctx.clone(),
                 integration_info: integration.frame.info().clone(),
                 storage: integration.frame.storage(),
+                renderer: Renderer::Glow,
                 gl: Some(gl),
                 get_proc_address: Some(&get_proc_address),
                 #[cfg(feature = "wgpu")]
@@ -324,6 +374,9 @@ impl<'app> GlowWinitApp<'app> {
             app_creator(&cc).map_err(crate::Error::AppCreation)?
         };
 
+        integration.report_storage_load_errors(app.as_mut());
+        integration.migrate_storage(app.as_mut());
+
         let glutin = Rc::new(RefCell::new(glutin));
 
         {
@@ -373,12 +426,39 @@ impl WinitApp for GlowWinitApp<'_> {
         }
     }
 
+    fn frame_rate_limit(&self) -> Option<f32> {
+        self.native_options.frame_rate_limit
+    }
+
+    fn control_flow_override(&self) -> Option<winit::event_loop::ControlFlow> {
+        self.native_options.control_flow_override
+    }
+
+    fn exit_code(&self) -> i32 {
+        self.running
+            .as_ref()
+            .map_or(0, |running| running.integration.frame.exit_code)
+    }
+
     fn window_id_from_viewport_id(&self, id: ViewportId) -> Option<WindowId> {
         self.running
             .as_ref()
             .and_then(|r| r.glutin.borrow().window_from_viewport.get(&id).copied())
     }
 
+    fn viewport_ids(&self) -> Vec<ViewportId> {
+        self.running.as_ref().map_or_else(Vec::new, |r| {
+            r.glutin.borrow().viewports.keys().copied().collect()
+        })
+    }
+
+    fn user_event_sender(&self) -> winit_integration::UserEventSender {
+        let event_loop_proxy = self.repaint_proxy.clone();
+        winit_integration::UserEventSender::new(self.user_events_sender.clone(), move || {
+            event_loop_proxy.lock().wake_up();
+        })
+    }
+
     fn save(&mut self) {
         log::debug!("WinitApp::save called");
         if let Some(running) = self.running.as_mut() {
@@ -427,9 +507,16 @@ impl WinitApp for GlowWinitApp<'_> {
                 .glutin
                 .borrow_mut()
                 .initialize_all_windows(event_loop);
+            running.app.on_resume(&running.integration.egui_ctx);
             running
         } else {
             // First resume event. Create our root window etc.
+            #[cfg(feature = "tray_icon")]
+            self.create_tray();
+            #[cfg(feature = "os_signals")]
+            if self.native_options.save_on_os_signal {
+                self.install_os_signal_handler();
+            }
             self.init_run_state(event_loop)?
         };
         let window_id = running.glutin.borrow().window_from_viewport[&ViewportId::ROOT];
@@ -438,6 +525,7 @@ impl WinitApp for GlowWinitApp<'_> {
 
     fn suspended(&mut self, _: &dyn ActiveEventLoop) -> crate::Result<EventResult> {
         if let Some(running) = &mut self.running {
+            running.app.on_suspend(&running.integration.egui_ctx);
             running.glutin.borrow_mut().on_suspend()?;
         }
         Ok(EventResult::Save)
@@ -509,6 +597,25 @@ impl WinitApp for GlowWinitApp<'_> {
 
         Ok(EventResult::Wait)
     }
+
+    #[cfg(feature = "tray_icon")]
+    fn on_tray_event(&mut self, id: u32) -> crate::Result<EventResult> {
+        if let Some(running) = &mut self.running {
+            running.app.on_tray_event(&running.integration.egui_ctx, id);
+            running.integration.egui_ctx.request_repaint();
+        }
+        Ok(EventResult::Wait)
+    }
+
+    fn on_user_event(&mut self, event: Box<dyn std::any::Any + Send>) -> Result<EventResult> {
+        if let Some(running) = &mut self.running {
+            running
+                .app
+                .on_user_event(&running.integration.egui_ctx, event.as_ref());
+            running.integration.egui_ctx.request_repaint();
+        }
+        Ok(EventResult::Wait)
+    }
 }
 
 impl GlowWinitRunning<'_> {
@@ -567,7 +674,7 @@ impl GlowWinitRunning<'_> {
             let mut raw_input = egui_winit.take_egui_input(window.as_ref());
             let viewport_ui_cb = viewport.viewport_ui_cb.clone();
 
-            self.integration.pre_update();
+            self.integration.pre_update(viewport_id, window.as_ref());
 
             raw_input.time = Some(self.integration.beginning.elapsed().as_secs_f64());
             raw_input.viewports = glutin
@@ -666,7 +773,9 @@ impl GlowWinitRunning<'_> {
 
         egui_winit.handle_platform_output(window.as_ref(), platform_output);
 
+        let tessellation_start = Instant::now();
         let clipped_primitives = integration.egui_ctx.tessellate(shapes, pixels_per_point);
+        let tessellation_time = tessellation_start.elapsed().as_secs_f32();
 
         {
             // We may need to switch contexts again, because of immediate viewports:
@@ -677,6 +786,8 @@ impl GlowWinitRunning<'_> {
 
         let screen_size_in_pixels: [u32; 2] = window.surface_size().into();
 
+        let paint_start = Instant::now();
+
         if !clear_before_update {
             painter.clear(screen_size_in_pixels, clear_color);
         }
@@ -688,6 +799,17 @@ impl GlowWinitRunning<'_> {
             &textures_delta,
         );
 
+        if viewport_id == ViewportId::ROOT {
+            integration.frame.info.tessellation_time = Some(tessellation_time);
+            integration.frame.info.paint_time = Some(paint_start.elapsed().as_secs_f32());
+        }
+
+        if viewport_id == ViewportId::ROOT && integration.frame.screenshot_requested.take() {
+            let screenshot = painter.read_screen_rgba(screen_size_in_pixels);
+            integration.frame.screenshot.set(Some(screenshot));
+            integration.egui_ctx.request_repaint(); // so the app gets it on the next frame
+        }
+
         {
             for action in viewport.actions_requested.drain() {
                 match action {
@@ -722,7 +844,7 @@ impl GlowWinitRunning<'_> {
                 }
             }
 
-            integration.post_rendering(window.as_ref());
+            integration.post_rendering(app.as_mut(), viewport_id, window.as_ref());
         }
 
         {
@@ -861,9 +983,13 @@ impl GlowWinitRunning<'_> {
                 if let (Some(window), Some(egui_winit)) =
                     (&viewport.window, &mut viewport.egui_winit)
                 {
-                    event_response =
-                        self.integration
-                            .on_window_event(window.as_ref(), egui_winit, event);
+                    event_response = self.integration.on_window_event(
+                        self.app.as_mut(),
+                        viewport_id,
+                        window.as_ref(),
+                        egui_winit,
+                        event,
+                    );
                 }
             } else {
                 log::trace!("Ignoring event: no viewport for {viewport_id:?}");
@@ -977,7 +1103,10 @@ impl GlutinWindowContext {
             //
             // The justification for FallbackEgl over PreferEgl is at https://github.com/emilk/egui/pull/2526#issuecomment-1400229576 .
             .with_preference(glutin_winit::ApiPreference::FallbackEgl);
-        todo!("Implement glutin");
+        Err(crate::Error::Glutin(
+            glutin::error::ErrorKind::NotSupported("creating a glutin display is not implemented")
+                .into(),
+        ))
         // .with_window_attributes(Some(egui_winit::create_winit_window_attributes(
         //     egui_ctx,
         //     viewport_builder.clone(),
@@ -1093,6 +1222,7 @@ impl GlutinWindowContext {
         //     max_texture_side: None,
         //     window_from_viewport,
         //     focused_viewport: Some(ViewportId::ROOT),
+        //     viewport_builder_hook: native_options.viewport_builder_hook.clone(),
         // };
 
         // slf.initialize_window(ViewportId::ROOT, event_loop)?;
@@ -1122,7 +1252,10 @@ impl GlutinWindowContext {
         viewport_id: ViewportId,
         event_loop: &dyn ActiveEventLoop,
     ) -> Result {
-        todo!("Glutin");
+        Err(crate::Error::Glutin(
+            glutin::error::ErrorKind::NotSupported("creating a glutin window is not implemented")
+                .into(),
+        ))
         // profiling::function_scope!();
 
         // let viewport = self
@@ -1326,6 +1459,7 @@ impl GlutinWindowContext {
                 class,
                 builder,
                 viewport_ui_cb,
+                self.viewport_builder_hook.as_ref(),
             );
 
             if let Some(window) = &viewport.window {
@@ -1358,15 +1492,20 @@ impl GlutinWindowContext {
     }
 }
 
-fn initialize_or_update_viewport(
-    viewports: &mut ViewportIdMap<Viewport>,
+fn initialize_or_update_viewport<'a>(
+    viewports: &'a mut ViewportIdMap<Viewport>,
     ids: ViewportIdPair,
     class: ViewportClass,
     mut builder: ViewportBuilder,
     viewport_ui_cb: Option<Arc<dyn Fn(&egui::Context) + Send + Sync>>,
-) -> &mut Viewport {
+    viewport_builder_hook: Option<&ViewportBuilderHook>,
+) -> &'a mut Viewport {
     profiling::function_scope!();
 
+    if let Some(hook) = viewport_builder_hook {
+        builder = hook(ids.this, builder);
+    }
+
     if builder.icon.is_none() {
         // Inherit icon from parent
         builder.icon = viewports
@@ -1441,6 +1580,7 @@ fn render_immediate_viewport(
 
     {
         let mut glutin = glutin.borrow_mut();
+        let glutin = &mut *glutin;
 
         initialize_or_update_viewport(
             &mut glutin.viewports,
@@ -1448,6 +1588,7 @@ fn render_immediate_viewport(
             ViewportClass::Immediate,
             builder,
             None,
+            glutin.viewport_builder_hook.as_ref(),
         );
 
         let ret = event_loop_context::with_current_event_loop(|event_loop| {
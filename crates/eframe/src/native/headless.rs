@@ -0,0 +1,493 @@
+//! Running an [`App`] without a window, rendering each frame to an image using [`egui_wgpu`].
+//!
+//! Useful for golden-image testing of real apps, or for rendering egui scenes on a server.
+
+use std::{iter::once, path::PathBuf};
+
+use egui::{ColorImage, RawInput, Vec2, ViewportCommand, ViewportId};
+use egui_wgpu::{RenderState, ScreenDescriptor, WgpuError, wgpu};
+use raw_window_handle::HandleError;
+
+use crate::{AppCreator, CreationContext, Error, NativeOptions, Renderer, Result, epi::ExitResult};
+
+use super::{epi_integration, epi_integration::EpiIntegration, winit_integration};
+
+/// Options for [`crate::run_headless`].
+#[derive(Clone)]
+pub struct HeadlessOptions {
+    /// Size of the offscreen surface, in points.
+    ///
+    /// Used for [`RawInput::screen_rect`] unless the input already sets it.
+    ///
+    /// Default: `800x600`.
+    pub size: Vec2,
+
+    /// The scale factor of the offscreen surface.
+    ///
+    /// The produced images are `size * pixels_per_point` pixels large.
+    ///
+    /// Default: `1.0`.
+    pub pixels_per_point: f32,
+
+    /// Controls whether to apply dithering to minimize banding artifacts.
+    ///
+    /// Default: `true`.
+    pub dithering: bool,
+
+    /// Load the app state from this file, and save it back when done
+    /// (and whenever [`crate::App::auto_save_interval`] has passed).
+    ///
+    /// Only used with the `persistence` feature.
+    ///
+    /// Default: `None`, so nothing is loaded or saved.
+    pub persistence_path: Option<PathBuf>,
+
+    /// Configures wgpu instance/device/adapter/surface creation.
+    pub wgpu_options: egui_wgpu::WgpuConfiguration,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            size: egui::vec2(800.0, 600.0),
+            pixels_per_point: 1.0,
+            dithering: true,
+            persistence_path: None,
+            wgpu_options: egui_wgpu::WgpuConfiguration::default(),
+        }
+    }
+}
+
+/// What [`crate::run_headless`] produced.
+#[derive(Clone, Debug, Default)]
+pub struct HeadlessOutput {
+    /// What the app painted in each frame, in the same order as the inputs.
+    pub images: Vec<ColorImage>,
+
+    /// Set with [`crate::Frame::set_exit_code`].
+    pub exit: ExitResult,
+}
+
+/// See [`crate::run_headless`].
+pub fn run_headless(
+    options: HeadlessOptions,
+    app_creator: AppCreator<'_>,
+    frames: impl Iterator<Item = RawInput>,
+) -> Result<HeadlessOutput> {
+    let HeadlessOptions {
+        size,
+        pixels_per_point,
+        dithering,
+        persistence_path,
+        wgpu_options,
+    } = options;
+
+    let instance = pollster::block_on(wgpu_options.wgpu_setup.new_instance());
+    let render_state = pollster::block_on(RenderState::create(
+        &wgpu_options,
+        &instance,
+        None,
+        None,
+        1,
+        dithering,
+    ))?;
+
+    let storage = persistence_path.and_then(epi_integration::create_storage_with_file);
+    let egui_ctx = winit_integration::create_egui_context(storage.as_deref(), None);
+    egui_ctx.set_embed_viewports(true);
+
+    let native_options = NativeOptions::default();
+    let mut integration = EpiIntegration::new(
+        egui_ctx.clone(),
+        None,
+        "",
+        &native_options,
+        storage,
+        #[cfg(feature = "glow")]
+        None,
+        #[cfg(feature = "glow")]
+        None,
+        Some(render_state.clone()),
+    );
+
+    let cc = CreationContext {
+        egui_ctx: egui_ctx.clone(),
+        integration_info: integration.frame.info().clone(),
+        storage: integration.frame.storage(),
+        renderer: Renderer::Wgpu,
+        #[cfg(feature = "glow")]
+        gl: None,
+        #[cfg(feature = "glow")]
+        get_proc_address: None,
+        wgpu_render_state: Some(render_state.clone()),
+        raw_display_handle: Err(HandleError::NotSupported),
+        raw_window_handle: Err(HandleError::NotSupported),
+    };
+    let mut app = {
+        profiling::scope!("user_app_creator");
+        app_creator(&cc).map_err(Error::AppCreation)?
+    };
+
+    integration.report_storage_load_errors(app.as_mut());
+    integration.migrate_storage(app.as_mut());
+
+    let mut images = Vec::new();
+    let mut title = None;
+    let mut close_requested = false;
+    let mut pending_events = Vec::new();
+
+    for mut raw_input in frames {
+        profiling::scope!("headless_frame");
+        let frame_start = web_time::Instant::now();
+
+        raw_input
+            .screen_rect
+            .get_or_insert_with(|| egui::Rect::from_min_size(egui::Pos2::ZERO, size));
+        raw_input.events.append(&mut pending_events);
+        let viewport = raw_input.viewports.entry(ViewportId::ROOT).or_default();
+        viewport
+            .native_pixels_per_point
+            .get_or_insert(pixels_per_point);
+        if viewport.title.is_none() {
+            viewport.title.clone_from(&title);
+        }
+        if std::mem::take(&mut close_requested) {
+            viewport.events.push(egui::ViewportEvent::Close);
+        }
+
+        let full_output = integration.update_with_input_time(app.as_mut(), None, raw_input);
+
+        // There is no window to apply the viewport commands to,
+        // so we handle the ones that make sense without one:
+        let mut screenshot_commands = Vec::new();
+        if let Some(output) = full_output.viewport_output.get(&ViewportId::ROOT) {
+            for command in &output.commands {
+                match command {
+                    ViewportCommand::Close => close_requested = true,
+                    ViewportCommand::Title(new_title) => title = Some(new_title.clone()),
+                    ViewportCommand::Screenshot(user_data) => {
+                        screenshot_commands.push(user_data.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if integration.frame.screenshot_requested.take() {
+            screenshot_commands.push(epi_integration::frame_screenshot_user_data());
+        }
+
+        let clear_color = app.clear_color(&egui_ctx.style().visuals);
+        let image = render_to_image(&render_state, &egui_ctx, full_output, clear_color)?;
+
+        // Screenshots are delivered with the input of the next frame, like on a real window:
+        if !screenshot_commands.is_empty() {
+            let image = std::sync::Arc::new(image.clone());
+            pending_events.extend(screenshot_commands.into_iter().map(|user_data| {
+                egui::Event::Screenshot {
+                    viewport_id: ViewportId::ROOT,
+                    user_data,
+                    image: image.clone(),
+                }
+            }));
+        }
+
+        integration.post_rendering_with_size(
+            app.as_mut(),
+            ViewportId::ROOT,
+            [image.width() as u32, image.height() as u32],
+        );
+        images.push(image);
+
+        integration.report_frame_time(frame_start.elapsed().as_secs_f32());
+        integration.maybe_autosave(app.as_mut(), None);
+
+        if integration.should_close() {
+            log::debug!("The app closed the headless viewport");
+            break;
+        }
+    }
+
+    integration.save(app.as_mut(), None);
+
+    #[cfg(feature = "glow")]
+    app.on_exit(None);
+    #[cfg(not(feature = "glow"))]
+    app.on_exit();
+
+    Ok(HeadlessOutput {
+        images,
+        exit: ExitResult {
+            code: integration.frame.exit_code,
+        },
+    })
+}
+
+/// Paint the output of one frame to an offscreen texture and read it back.
+fn render_to_image(
+    render_state: &RenderState,
+    egui_ctx: &egui::Context,
+    full_output: egui::FullOutput,
+    clear_color: [f32; 4],
+) -> Result<ColorImage> {
+    profiling::function_scope!();
+
+    let RenderState {
+        device,
+        queue,
+        renderer,
+        target_format,
+        ..
+    } = render_state;
+    let mut renderer = renderer.write();
+
+    let pixels_per_point = full_output.pixels_per_point;
+    let size = egui_ctx.screen_rect().size() * pixels_per_point;
+    let screen = ScreenDescriptor {
+        pixels_per_point,
+        size_in_pixels: [
+            (size.x.round() as u32).max(1),
+            (size.y.round() as u32).max(1),
+        ],
+    };
+
+    for (id, image_delta) in &full_output.textures_delta.set {
+        renderer.update_texture(device, queue, *id, image_delta);
+    }
+
+    let clipped_primitives = egui_ctx.tessellate(full_output.shapes, pixels_per_point);
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("headless_encoder"),
+    });
+
+    let user_cmd_bufs =
+        renderer.update_buffers(device, queue, &mut encoder, &clipped_primitives, &screen);
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("headless_target"),
+        size: wgpu::Extent3d {
+            width: screen.size_in_pixels[0],
+            height: screen.size_in_pixels[1],
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: *target_format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    {
+        let mut render_pass = encoder
+            .begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("headless_render_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: clear_color[0] as f64,
+                            g: clear_color[1] as f64,
+                            b: clear_color[2] as f64,
+                            a: clear_color[3] as f64,
+                        }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            })
+            .forget_lifetime();
+
+        renderer.render(&mut render_pass, &clipped_primitives, &screen);
+    }
+
+    // Copy the texture to a buffer we can map, respecting the row alignment wgpu requires:
+    let bytes_per_pixel = 4;
+    let unpadded_bytes_per_row = screen.size_in_pixels[0] * bytes_per_pixel;
+    let padded_bytes_per_row =
+        wgpu::util::align_to(unpadded_bytes_per_row, wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("headless_readback"),
+        size: (padded_bytes_per_row * screen.size_in_pixels[1]) as u64,
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        texture.size(),
+    );
+
+    let submission_index = queue.submit(user_cmd_bufs.into_iter().chain(once(encoder.finish())));
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    buffer
+        .slice(..)
+        .map_async(wgpu::MapMode::Read, move |result| drop(sender.send(result)));
+    device
+        .poll(wgpu::PollType::WaitForSubmissionIndex(submission_index))
+        .map_err(WgpuError::from)?;
+    receiver
+        .recv()
+        .unwrap_or(Err(wgpu::BufferAsyncError)) // wgpu dropped the callback without calling it
+        .map_err(WgpuError::from)?;
+
+    let bgra = *target_format == wgpu::TextureFormat::Bgra8Unorm;
+    let pixels = buffer
+        .slice(..)
+        .get_mapped_range()
+        .chunks_exact(padded_bytes_per_row as usize)
+        .flat_map(|row| row[..unpadded_bytes_per_row as usize].chunks_exact(4))
+        .map(|c| {
+            if bgra {
+                egui::Color32::from_rgba_premultiplied(c[2], c[1], c[0], c[3])
+            } else {
+                egui::Color32::from_rgba_premultiplied(c[0], c[1], c[2], c[3])
+            }
+        })
+        .collect();
+    buffer.unmap();
+
+    for id in &full_output.textures_delta.free {
+        renderer.free_texture(id);
+    }
+
+    Ok(ColorImage::new(
+        [
+            screen.size_in_pixels[0] as usize,
+            screen.size_in_pixels[1] as usize,
+        ],
+        pixels,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `app` headless for `n_frames` frames.
+    ///
+    /// The tests calling this need a wgpu adapter (a GPU, or a software rasterizer like lavapipe),
+    /// so they are ignored by default. Run them with `cargo test -- --ignored`.
+    fn run(
+        options: HeadlessOptions,
+        n_frames: usize,
+        app: impl crate::App + 'static,
+    ) -> HeadlessOutput {
+        let frames = std::iter::repeat_with(RawInput::default).take(n_frames);
+        run_headless(options, Box::new(|_cc| Ok(Box::new(app))), frames)
+            .unwrap_or_else(|err| panic!("Failed to run headless: {err}"))
+    }
+
+    struct Label;
+
+    impl crate::App for Label {
+        fn update(&mut self, ctx: &egui::Context, _frame: &mut crate::Frame) {
+            egui::CentralPanel::default().show(ctx, |ui| ui.label("Hello"));
+        }
+    }
+
+    #[test]
+    #[ignore = "needs a wgpu adapter"]
+    fn images_are_size_times_pixels_per_point() {
+        let options = HeadlessOptions {
+            size: egui::vec2(64.0, 32.0),
+            pixels_per_point: 2.0,
+            ..Default::default()
+        };
+        let output = run(options, 2, Label);
+        assert_eq!(output.images.len(), 2);
+        for image in &output.images {
+            assert_eq!(image.size, [128, 64]);
+        }
+    }
+
+    struct CloseWithExitCode;
+
+    impl crate::App for CloseWithExitCode {
+        fn update(&mut self, ctx: &egui::Context, frame: &mut crate::Frame) {
+            frame.set_exit_code(3);
+            ctx.send_viewport_cmd(ViewportCommand::Close);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs a wgpu adapter"]
+    fn close_stops_the_run() {
+        let output = run(HeadlessOptions::default(), 5, CloseWithExitCode);
+        // The close request is seen in the frame after the one that sent it:
+        assert_eq!(output.images.len(), 2);
+        assert_eq!(output.exit.code, 3);
+    }
+
+    /// Takes screenshots with [`crate::Frame::screenshot`] or [`crate::Frame::request_screenshot`].
+    struct Screenshots {
+        use_callback: bool,
+        images: std::rc::Rc<std::cell::RefCell<Vec<ColorImage>>>,
+    }
+
+    impl crate::App for Screenshots {
+        fn update(&mut self, ctx: &egui::Context, frame: &mut crate::Frame) {
+            egui::CentralPanel::default().show(ctx, |ui| ui.label("Hello"));
+            if self.use_callback {
+                if ctx.cumulative_frame_nr() == 0 {
+                    frame.request_screenshot();
+                }
+            } else if let Some(image) = frame.screenshot() {
+                self.images.borrow_mut().push(image);
+            }
+        }
+
+        fn on_screenshot(&mut self, image: ColorImage) {
+            self.images.borrow_mut().push(image);
+        }
+    }
+
+    fn screenshots(use_callback: bool) -> Vec<ColorImage> {
+        let images = std::rc::Rc::default();
+        let app = Screenshots {
+            use_callback,
+            images: std::rc::Rc::clone(&images),
+        };
+        let options = HeadlessOptions {
+            size: egui::vec2(64.0, 32.0),
+            pixels_per_point: 2.0,
+            ..Default::default()
+        };
+        run(options, 4, app);
+        images.take()
+    }
+
+    #[test]
+    #[ignore = "needs a wgpu adapter"]
+    fn screenshot_matches_window_size() {
+        let images = screenshots(false);
+        assert!(
+            !images.is_empty(),
+            "Frame::screenshot never returned an image"
+        );
+        for image in &images {
+            assert_eq!(image.size, [128, 64]);
+            assert_eq!(image.pixels.len(), 128 * 64);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs a wgpu adapter"]
+    fn request_screenshot_calls_on_screenshot_once() {
+        let images = screenshots(true);
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].pixels.len(), 128 * 64);
+    }
+}
@@ -1,7 +1,9 @@
 mod app_icon;
-mod epi_integration;
+pub(crate) mod app_loader;
+pub(crate) mod epi_integration;
 mod event_loop_context;
 pub mod run;
+pub(crate) mod storage_dir;
 
 /// File storage which can be used by native backends.
 #[cfg(feature = "persistence")]
@@ -9,8 +11,14 @@ pub mod file_storage;
 
 pub(crate) mod winit_integration;
 
+#[cfg(feature = "tray_icon")]
+mod tray;
+
 #[cfg(feature = "glow")]
 mod glow_integration;
 
 #[cfg(feature = "wgpu")]
 mod wgpu_integration;
+
+#[cfg(feature = "wgpu")]
+pub(crate) mod headless;
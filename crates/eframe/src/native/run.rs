@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use winit::{
     application::ApplicationHandler,
@@ -6,9 +6,10 @@ use winit::{
     window::WindowId,
 };
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
+use egui::ViewportId;
 
-use super::winit_integration::{UserEvent, WinitApp};
+use super::winit_integration::{UserEvent, UserEventSender, WinitApp};
 use crate::{
     Result, epi,
     native::{event_loop_context, winit_integration::EventResult},
@@ -67,21 +68,152 @@ fn with_event_loop<R>(
 /// some events, but otherwise forwards events to the [`WinitApp`].
 struct WinitAppWrapper<T: WinitApp> {
     windows_next_repaint_times: HashMap<WindowId, Instant>,
+
+    /// When we last painted each window. Only tracked if there is a [`Self::min_frame_time`].
+    windows_last_frame_times: HashMap<WindowId, Instant>,
+
+    /// From [`epi::NativeOptions::frame_rate_limit`].
+    min_frame_time: Option<Duration>,
+
+    /// From [`epi::NativeOptions::control_flow_override`].
+    control_flow_override: Option<ControlFlow>,
+
+    /// Windows that are fully hidden behind other windows, according to winit.
+    ///
+    /// These are only repainted every [`OCCLUDED_REPAINT_INTERVAL`].
+    occluded_windows: HashSet<WindowId>,
+
     winit_app: T,
     return_result: Result<(), crate::Error>,
+
+    /// From [`epi::Frame::set_exit_code`], read when the app exits.
+    exit_code: i32,
     run_and_return: bool,
 }
 
 impl<T: WinitApp> WinitAppWrapper<T> {
     fn new(winit_app: T, run_and_return: bool) -> Self {
+        let min_frame_time = winit_app
+            .frame_rate_limit()
+            .filter(|fps| fps.is_finite() && 0.0 < *fps)
+            .map(|fps| Duration::from_secs_f32(1.0 / fps));
+
+        let control_flow_override = winit_app.control_flow_override();
+
         Self {
             windows_next_repaint_times: HashMap::default(),
+            windows_last_frame_times: HashMap::default(),
+            min_frame_time,
+            control_flow_override,
+            occluded_windows: HashSet::default(),
             winit_app,
             return_result: Ok(()),
+            exit_code: 0,
             run_and_return,
         }
     }
 
+    /// Postpone a repaint so that we respect [`epi::NativeOptions::frame_rate_limit`],
+    /// and don't waste time painting occluded windows.
+    ///
+    /// Only repaints that are already due are limited.
+    /// A repaint scheduled for later (with [`egui::Context::request_repaint_after`])
+    /// is kept, even if it is sooner than the frame rate limit allows.
+    fn limit_repaint_time(
+        &self,
+        window_id: WindowId,
+        repaint_time: Instant,
+        now: Instant,
+    ) -> Instant {
+        let repaint_time = match (
+            self.min_frame_time,
+            self.windows_last_frame_times.get(&window_id),
+        ) {
+            (Some(min_frame_time), Some(last_frame_time)) if repaint_time <= now => {
+                earliest_repaint_time(repaint_time, *last_frame_time, min_frame_time)
+            }
+            _ => repaint_time,
+        };
+
+        if self.occluded_windows.contains(&window_id) {
+            repaint_time.max(now + OCCLUDED_REPAINT_INTERVAL)
+        } else {
+            repaint_time
+        }
+    }
+
+    /// Remember when to repaint the window of `event_result`, if it wants a repaint.
+    fn schedule_repaint(&mut self, event_result: EventResult, now: Instant) {
+        match event_result {
+            EventResult::RepaintNow(window_id) => {
+                log::trace!("RepaintNow of {window_id:?}",);
+                self.windows_next_repaint_times.insert(window_id, now);
+            }
+            EventResult::RepaintNext(window_id) => {
+                log::trace!("RepaintNext of {window_id:?}",);
+                let repaint_time = self.limit_repaint_time(window_id, now, now);
+                self.windows_next_repaint_times
+                    .insert(window_id, repaint_time);
+            }
+            EventResult::RepaintAt(window_id, repaint_time) => {
+                let repaint_time = self.limit_repaint_time(window_id, repaint_time, now);
+                self.windows_next_repaint_times.insert(
+                    window_id,
+                    self.windows_next_repaint_times
+                        .get(&window_id)
+                        .map_or(repaint_time, |last| (*last).min(repaint_time)),
+                );
+            }
+            EventResult::Wait | EventResult::Save | EventResult::Exit => {}
+        }
+    }
+
+    /// Call when a window is about to be painted.
+    fn on_frame_start(&mut self, window_id: WindowId, now: Instant) {
+        if self.min_frame_time.is_some() {
+            self.windows_last_frame_times.insert(window_id, now);
+        }
+    }
+
+    /// The windows whose repaint is due at `now`. They are no longer scheduled for repaint.
+    fn take_due_repaints(&mut self, now: Instant) -> Vec<WindowId> {
+        let mut due = Vec::new();
+        self.windows_next_repaint_times
+            .retain(|window_id, repaint_time| {
+                if now < *repaint_time {
+                    return true; // not yet ready
+                };
+                due.push(*window_id);
+                false
+            });
+        due
+    }
+
+    /// Respects [`epi::NativeOptions::control_flow_override`].
+    fn set_control_flow(&self, event_loop: &dyn ActiveEventLoop, control_flow: ControlFlow) {
+        event_loop.set_control_flow(self.control_flow_override.unwrap_or(control_flow));
+    }
+
+    fn on_occluded(&mut self, window_id: WindowId, occluded: bool) {
+        if occluded {
+            log::trace!("{window_id:?} is occluded - throttling repaints");
+            self.occluded_windows.insert(window_id);
+        } else if self.occluded_windows.remove(&window_id) {
+            log::trace!("{window_id:?} is visible again - repainting");
+            self.windows_next_repaint_times
+                .insert(window_id, Instant::now());
+        }
+    }
+
+    fn run_ui_and_paint(
+        &mut self,
+        event_loop: &dyn ActiveEventLoop,
+        window_id: WindowId,
+    ) -> Result<EventResult> {
+        self.on_frame_start(window_id, Instant::now());
+        self.winit_app.run_ui_and_paint(event_loop, window_id)
+    }
+
     fn handle_event_result(
         &mut self,
         event_loop: &dyn ActiveEventLoop,
@@ -94,6 +226,15 @@ impl<T: WinitApp> WinitAppWrapper<T> {
 
         let mut event_result = event_result;
 
+        if let Ok(EventResult::RepaintNow(window_id) | EventResult::RepaintNext(window_id)) =
+            event_result
+        {
+            if self.occluded_windows.contains(&window_id) {
+                // Nobody can see it, but keep waking up now and then so the app can handle timers:
+                event_result = Ok(EventResult::RepaintAt(window_id, Instant::now()));
+            }
+        }
+
         if cfg!(target_os = "windows") {
             if let Ok(EventResult::RepaintNow(window_id)) = event_result {
                 log::trace!("RepaintNow of {window_id:?}");
@@ -101,44 +242,28 @@ impl<T: WinitApp> WinitAppWrapper<T> {
                     .insert(window_id, Instant::now());
 
                 // Fix flickering on Windows, see https://github.com/emilk/egui/pull/2280
-                event_result = self.winit_app.run_ui_and_paint(event_loop, window_id);
+                event_result = self.run_ui_and_paint(event_loop, window_id);
             }
         }
 
-        let combined_result = event_result.map(|event_result| match event_result {
-            EventResult::Wait => {
-                event_loop.set_control_flow(ControlFlow::Wait);
-                event_result
-            }
-            EventResult::RepaintNow(window_id) => {
-                log::trace!("RepaintNow of {window_id:?}",);
-                self.windows_next_repaint_times
-                    .insert(window_id, Instant::now());
-                event_result
-            }
-            EventResult::RepaintNext(window_id) => {
-                log::trace!("RepaintNext of {window_id:?}",);
-                self.windows_next_repaint_times
-                    .insert(window_id, Instant::now());
-                event_result
-            }
-            EventResult::RepaintAt(window_id, repaint_time) => {
-                self.windows_next_repaint_times.insert(
-                    window_id,
-                    self.windows_next_repaint_times
-                        .get(&window_id)
-                        .map_or(repaint_time, |last| (*last).min(repaint_time)),
-                );
-                event_result
-            }
-            EventResult::Save => {
-                save = true;
-                event_result
-            }
-            EventResult::Exit => {
-                exit = true;
-                event_result
+        let combined_result = event_result.map(|event_result| {
+            match event_result {
+                EventResult::Wait => {
+                    self.set_control_flow(event_loop, ControlFlow::Wait);
+                }
+                EventResult::RepaintNow(_)
+                | EventResult::RepaintNext(_)
+                | EventResult::RepaintAt(..) => {
+                    self.schedule_repaint(event_result, Instant::now());
+                }
+                EventResult::Save => {
+                    save = true;
+                }
+                EventResult::Exit => {
+                    exit = true;
+                }
             }
+            event_result
         });
 
         if let Err(err) = combined_result {
@@ -153,6 +278,8 @@ impl<T: WinitApp> WinitAppWrapper<T> {
         }
 
         if exit {
+            self.exit_code = self.winit_app.exit_code();
+
             if self.run_and_return {
                 log::debug!("Asking to exit event loop…");
                 event_loop.exit();
@@ -160,9 +287,9 @@ impl<T: WinitApp> WinitAppWrapper<T> {
                 log::debug!("Quitting - saving app state…");
                 self.winit_app.save_and_destroy();
 
-                log::debug!("Exiting with return code 0");
+                log::debug!("Exiting with return code {}", self.exit_code);
 
-                std::process::exit(0);
+                std::process::exit(self.exit_code);
             }
         }
 
@@ -170,32 +297,38 @@ impl<T: WinitApp> WinitAppWrapper<T> {
     }
 
     fn check_redraw_requests(&mut self, event_loop: &dyn ActiveEventLoop) {
-        let now = Instant::now();
-
-        self.windows_next_repaint_times
-            .retain(|window_id, repaint_time| {
-                if now < *repaint_time {
-                    return true; // not yet ready
-                };
-
-                event_loop.set_control_flow(ControlFlow::Poll);
+        let due_repaints = self.take_due_repaints(Instant::now());
+        if !due_repaints.is_empty() {
+            self.set_control_flow(event_loop, ControlFlow::Poll);
+        }
 
-                if let Some(window) = self.winit_app.window(*window_id) {
-                    log::trace!("request_redraw for {window_id:?}");
-                    window.request_redraw();
-                } else {
-                    log::trace!("No window found for {window_id:?}");
-                }
-                false
-            });
+        for window_id in due_repaints {
+            if let Some(window) = self.winit_app.window(window_id) {
+                log::trace!("request_redraw for {window_id:?}");
+                window.request_redraw();
+            } else {
+                log::trace!("No window found for {window_id:?}");
+            }
+        }
 
-        let next_repaint_time = self.windows_next_repaint_times.values().min().copied();
-        if let Some(next_repaint_time) = next_repaint_time {
-            event_loop.set_control_flow(ControlFlow::WaitUntil(next_repaint_time));
+        if let Some(next_repaint_time) = self.next_repaint_time() {
+            self.set_control_flow(event_loop, ControlFlow::WaitUntil(next_repaint_time));
         };
     }
 }
 
+/// How often we repaint a window that is fully hidden behind other windows.
+const OCCLUDED_REPAINT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The earliest we may repaint a window that was last painted at `last_frame_time`.
+fn earliest_repaint_time(
+    repaint_time: Instant,
+    last_frame_time: Instant,
+    min_frame_time: Duration,
+) -> Instant {
+    repaint_time.max(last_frame_time + min_frame_time)
+}
+
 impl<T: WinitApp> ApplicationHandler for WinitAppWrapper<T> {
     fn can_create_surfaces(&mut self, event_loop: &dyn ActiveEventLoop) {
         self.resumed(event_loop);
@@ -250,6 +383,11 @@ impl<T: WinitApp> ApplicationHandler for WinitAppWrapper<T> {
                 UserEvent::RequestRepaint { .. } => "UserEvent::RequestRepaint",
                 #[cfg(feature = "accesskit")]
                 UserEvent::AccessKitActionRequest(_) => "UserEvent::AccessKitActionRequest",
+                #[cfg(feature = "tray_icon")]
+                UserEvent::TrayMenuActivated(_) => "UserEvent::TrayMenuActivated",
+                #[cfg(feature = "os_signals")]
+                UserEvent::OsSignal => "UserEvent::OsSignal",
+                UserEvent::User(_) => "UserEvent::User",
             });
 
             event_loop_context::with_event_loop_context(event_loop, move || {
@@ -283,6 +421,15 @@ impl<T: WinitApp> ApplicationHandler for WinitAppWrapper<T> {
                     UserEvent::AccessKitActionRequest(request) => {
                         self.winit_app.on_accesskit_event(request)
                     }
+                    #[cfg(feature = "tray_icon")]
+                    UserEvent::TrayMenuActivated(id) => self.winit_app.on_tray_event(id),
+                    #[cfg(feature = "os_signals")]
+                    UserEvent::OsSignal => {
+                        log::debug!("Received a termination signal - saving app state…");
+                        self.winit_app.save_and_destroy();
+                        Ok(EventResult::Exit)
+                    }
+                    UserEvent::User(event) => self.winit_app.on_user_event(event),
                 };
                 self.handle_event_result(event_loop, event_result);
             });
@@ -306,10 +453,20 @@ impl<T: WinitApp> ApplicationHandler for WinitAppWrapper<T> {
         profiling::function_scope!(egui_winit::short_window_event_description(&event));
 
         // Nb: Make sure this guard is dropped after this function returns.
+        match &event {
+            winit::event::WindowEvent::Occluded(occluded) => {
+                self.on_occluded(window_id, *occluded);
+            }
+            winit::event::WindowEvent::Destroyed => {
+                self.occluded_windows.remove(&window_id);
+            }
+            _ => {}
+        }
+
         event_loop_context::with_event_loop_context(event_loop, move || {
             let event_result = match event {
                 winit::event::WindowEvent::RedrawRequested => {
-                    self.winit_app.run_ui_and_paint(event_loop, window_id)
+                    self.run_ui_and_paint(event_loop, window_id)
                 }
                 _ => self.winit_app.window_event(event_loop, window_id, event),
             };
@@ -320,7 +477,7 @@ impl<T: WinitApp> ApplicationHandler for WinitAppWrapper<T> {
 }
 
 #[cfg(not(target_os = "ios"))]
-fn run_and_return(event_loop: &mut EventLoop, winit_app: impl WinitApp) -> Result {
+fn run_and_return(event_loop: &mut EventLoop, winit_app: impl WinitApp) -> Result<epi::ExitResult> {
     use winit::event_loop::run_on_demand::EventLoopExtRunOnDemand as _;
 
     log::trace!("Entering the winit event loop (run_app_on_demand)…");
@@ -328,10 +485,12 @@ fn run_and_return(event_loop: &mut EventLoop, winit_app: impl WinitApp) -> Resul
     let mut app = WinitAppWrapper::new(winit_app, true);
     event_loop.run_app_on_demand(&mut app)?;
     log::debug!("eframe window closed");
-    app.return_result
+    app.return_result.map(|()| epi::ExitResult {
+        code: app.exit_code,
+    })
 }
 
-fn run_and_exit(event_loop: EventLoop, winit_app: impl WinitApp) -> Result {
+fn run_and_exit(event_loop: EventLoop, winit_app: impl WinitApp) -> Result<epi::ExitResult> {
     log::trace!("Entering the winit event loop (run_app)…");
 
     // When to repaint what window
@@ -339,7 +498,27 @@ fn run_and_exit(event_loop: EventLoop, winit_app: impl WinitApp) -> Result {
     event_loop.run_app(&mut app)?;
 
     log::debug!("winit event loop unexpectedly returned");
-    Ok(())
+    Ok(epi::ExitResult {
+        code: app.exit_code,
+    })
+}
+
+/// Run on an event loop supplied by the user, respecting [`epi::NativeOptions::run_and_return`].
+#[cfg_attr(target_os = "ios", expect(unused_mut))]
+fn run_on_event_loop(
+    mut event_loop: EventLoop,
+    native_options_run_and_return: bool,
+    winit_app: impl WinitApp,
+) -> Result<epi::ExitResult> {
+    #[cfg(not(target_os = "ios"))]
+    if native_options_run_and_return {
+        return run_and_return(&mut event_loop, winit_app);
+    }
+
+    #[cfg(target_os = "ios")]
+    let _ = native_options_run_and_return;
+
+    run_and_exit(event_loop, winit_app)
 }
 
 // ----------------------------------------------------------------------------
@@ -349,7 +528,7 @@ pub fn run_glow(
     app_name: &str,
     mut native_options: epi::NativeOptions,
     app_creator: epi::AppCreator<'_>,
-) -> Result {
+) -> Result<epi::ExitResult> {
     #![allow(clippy::needless_return_with_question_mark)] // False positive
 
     use super::glow_integration::GlowWinitApp;
@@ -367,16 +546,32 @@ pub fn run_glow(
     run_and_exit(event_loop, glow_eframe)
 }
 
+#[cfg(feature = "glow")]
+pub fn run_glow_with_event_loop(
+    app_name: &str,
+    native_options: epi::NativeOptions,
+    app_creator: epi::AppCreator<'_>,
+    event_loop: EventLoop,
+) -> Result<epi::ExitResult> {
+    use super::glow_integration::GlowWinitApp;
+
+    let run_and_return = native_options.run_and_return;
+    let glow_eframe = GlowWinitApp::new(&event_loop, app_name, native_options, app_creator);
+    run_on_event_loop(event_loop, run_and_return, glow_eframe)
+}
+
 #[cfg(feature = "glow")]
 pub fn create_glow<'a>(
     app_name: &str,
     native_options: epi::NativeOptions,
     app_creator: epi::AppCreator<'a>,
     event_loop: &EventLoop,
-) -> impl ApplicationHandler + 'a {
+    egui_ctx: Option<egui::Context>,
+) -> impl EframeApplicationHandler + 'a {
     use super::glow_integration::GlowWinitApp;
 
-    let glow_eframe = GlowWinitApp::new(event_loop, app_name, native_options, app_creator);
+    let glow_eframe = GlowWinitApp::new(event_loop, app_name, native_options, app_creator)
+        .with_egui_ctx(egui_ctx);
     WinitAppWrapper::new(glow_eframe, true)
 }
 
@@ -387,7 +582,7 @@ pub fn run_wgpu(
     app_name: &str,
     mut native_options: epi::NativeOptions,
     app_creator: epi::AppCreator<'_>,
-) -> Result {
+) -> Result<epi::ExitResult> {
     #![allow(clippy::needless_return_with_question_mark)] // False positive
 
     use super::wgpu_integration::WgpuWinitApp;
@@ -405,28 +600,81 @@ pub fn run_wgpu(
     run_and_exit(event_loop, wgpu_eframe)
 }
 
+#[cfg(feature = "wgpu")]
+pub fn run_wgpu_with_event_loop(
+    app_name: &str,
+    native_options: epi::NativeOptions,
+    app_creator: epi::AppCreator<'_>,
+    event_loop: EventLoop,
+) -> Result<epi::ExitResult> {
+    use super::wgpu_integration::WgpuWinitApp;
+
+    let run_and_return = native_options.run_and_return;
+    let wgpu_eframe = WgpuWinitApp::new(&event_loop, app_name, native_options, app_creator);
+    run_on_event_loop(event_loop, run_and_return, wgpu_eframe)
+}
+
 #[cfg(feature = "wgpu")]
 pub fn create_wgpu<'a>(
     app_name: &str,
     native_options: epi::NativeOptions,
     app_creator: epi::AppCreator<'a>,
     event_loop: &EventLoop,
-) -> impl ApplicationHandler + 'a {
+    egui_ctx: Option<egui::Context>,
+) -> impl EframeApplicationHandler + 'a {
     use super::wgpu_integration::WgpuWinitApp;
 
-    let wgpu_eframe = WgpuWinitApp::new(event_loop, app_name, native_options, app_creator);
+    let wgpu_eframe = WgpuWinitApp::new(event_loop, app_name, native_options, app_creator)
+        .with_egui_ctx(egui_ctx);
     WinitAppWrapper::new(wgpu_eframe, true)
 }
 
 // ----------------------------------------------------------------------------
 
+/// What [`EframeWinitApplication`] needs from the app it wraps, beyond [`ApplicationHandler`].
+pub(crate) trait EframeApplicationHandler: ApplicationHandler {
+    fn viewport_ids(&self) -> Vec<ViewportId>;
+
+    fn user_event_sender(&self) -> UserEventSender;
+
+    /// The error that made the event loop exit, if any.
+    fn take_error(&mut self) -> Option<crate::Error>;
+
+    /// The earliest time at which a window should be repainted, if any.
+    ///
+    /// Unlike the [`ControlFlow`] of the event loop,
+    /// this is not hidden by [`epi::NativeOptions::control_flow_override`].
+    fn next_repaint_time(&self) -> Option<Instant>;
+}
+
+impl<T: WinitApp> EframeApplicationHandler for WinitAppWrapper<T> {
+    fn viewport_ids(&self) -> Vec<ViewportId> {
+        self.winit_app.viewport_ids()
+    }
+
+    fn user_event_sender(&self) -> UserEventSender {
+        self.winit_app.user_event_sender()
+    }
+
+    fn take_error(&mut self) -> Option<crate::Error> {
+        std::mem::replace(&mut self.return_result, Ok(())).err()
+    }
+
+    fn next_repaint_time(&self) -> Option<Instant> {
+        self.windows_next_repaint_times.values().min().copied()
+    }
+}
+
 /// A proxy to the eframe application that implements [`ApplicationHandler`].
 ///
 /// This can be run directly on your own [`EventLoop`] by itself or with other
 /// windows you manage outside of eframe.
 pub struct EframeWinitApplication<'a> {
-    wrapper: Box<dyn ApplicationHandler + 'a>,
+    wrapper: Box<dyn EframeApplicationHandler + 'a>,
     control_flow: ControlFlow,
+
+    /// The earliest time at which a window should be repainted, if any.
+    next_repaint_time: Option<Instant>,
 }
 
 impl ApplicationHandler for EframeWinitApplication<'_> {
@@ -467,6 +715,7 @@ impl ApplicationHandler for EframeWinitApplication<'_> {
     fn about_to_wait(&mut self, event_loop: &dyn ActiveEventLoop) {
         self.wrapper.about_to_wait(event_loop);
         self.control_flow = event_loop.control_flow();
+        self.next_repaint_time = self.wrapper.next_repaint_time();
     }
 
     fn suspended(&mut self, event_loop: &dyn ActiveEventLoop) {
@@ -483,17 +732,38 @@ impl ApplicationHandler for EframeWinitApplication<'_> {
 }
 
 impl<'a> EframeWinitApplication<'a> {
-    pub(crate) fn new<T: ApplicationHandler + 'a>(app: T) -> Self {
+    pub(crate) fn new<T: EframeApplicationHandler + 'a>(app: T) -> Self {
         Self {
             wrapper: Box::new(app),
             control_flow: ControlFlow::default(),
+            next_repaint_time: None,
         }
     }
 
+    /// All currently open viewports, including [`ViewportId::ROOT`].
+    ///
+    /// Useful for keeping track of eframe's windows when running on your own [`EventLoop`].
+    /// Empty until the app has been started.
+    pub fn viewport_ids(&self) -> Vec<ViewportId> {
+        self.wrapper.viewport_ids()
+    }
+
+    /// Send your own events into the event loop, to [`crate::App::on_user_event`].
+    ///
+    /// Unlike [`crate::Frame::event_loop_proxy`] this is available before the app has started,
+    /// e.g. to hand to a network thread right after [`crate::create_native`].
+    /// Events that arrive before the app has been created are dropped.
+    pub fn user_event_sender(&self) -> UserEventSender {
+        self.wrapper.user_event_sender()
+    }
+
     /// Pump the `EventLoop` to check for and dispatch pending events to this application.
     ///
     /// Returns either the exit code for the application or the final state of the [`ControlFlow`]
-    /// after all events have been dispatched in this iteration.
+    /// after all events have been dispatched in this iteration,
+    /// together with when eframe next wants to repaint.
+    /// If eframe hit an error (e.g. when setting up the graphics context or creating the app),
+    /// that error is returned instead.
     ///
     /// This is useful when your [`EventLoop`] is not the main event loop for your application.
     /// See the `external_eventloop_async` example.
@@ -505,23 +775,256 @@ impl<'a> EframeWinitApplication<'a> {
     ) -> EframePumpStatus {
         use winit::event_loop::pump_events::{EventLoopExtPumpEvents as _, PumpStatus};
 
-        match event_loop.pump_app_events(timeout, &mut *self) {
-            PumpStatus::Continue => EframePumpStatus::Continue(self.control_flow),
+        let status = event_loop.pump_app_events(timeout, &mut *self);
+
+        if let Some(err) = self.wrapper.take_error() {
+            return EframePumpStatus::Err(err);
+        }
+
+        match status {
+            PumpStatus::Continue => {
+                EframePumpStatus::Continue(self.control_flow, self.next_repaint_time)
+            }
             PumpStatus::Exit(code) => EframePumpStatus::Exit(code),
         }
     }
 }
 
-/// Either an exit code or a [`ControlFlow`] from the [`ActiveEventLoop`].
+/// Either an exit code, an error, or a [`ControlFlow`] from the [`ActiveEventLoop`].
 ///
 /// The result of [`EframeWinitApplication::pump_eframe_app`].
 #[cfg(not(target_os = "ios"))]
 pub enum EframePumpStatus {
-    /// The final state of the [`ControlFlow`] after all events have been dispatched
+    /// The final state of the [`ControlFlow`] after all events have been dispatched,
+    /// and the earliest time at which a window wants to be repainted (if any).
     ///
     /// Callers should perform the action that is appropriate for the [`ControlFlow`] value.
-    Continue(ControlFlow),
+    /// If there is a repaint deadline, it is safe to sleep until then (or until new events arrive)
+    /// instead of polling.
+    Continue(ControlFlow, Option<Instant>),
 
     /// The exit code for the application
     Exit(i32),
+
+    /// eframe stopped because of this error.
+    ///
+    /// The app has been asked to exit, so you should stop pumping events.
+    Err(crate::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use winit::{
+        event_loop::{ActiveEventLoop, ControlFlow},
+        window::WindowId,
+    };
+
+    use super::{EframeApplicationHandler as _, WinitAppWrapper, earliest_repaint_time};
+    use crate::{
+        Result,
+        native::winit_integration::{EventResult, UserEvent, UserEventSender, WinitApp},
+    };
+
+    /// An app without windows, that asks for a repaint `repaint_after` every frame.
+    struct FakeApp {
+        frame_rate_limit: Option<f32>,
+        repaint_after: Duration,
+        frames_painted: usize,
+        control_flow_override: Option<ControlFlow>,
+    }
+
+    impl FakeApp {
+        /// Stands in for [`WinitApp::run_ui_and_paint`], which needs a real event loop.
+        fn paint(&mut self, window_id: WindowId, now: Instant) -> EventResult {
+            self.frames_painted += 1;
+            EventResult::RepaintAt(window_id, now + self.repaint_after)
+        }
+    }
+
+    impl WinitApp for FakeApp {
+        fn egui_ctx(&self) -> Option<&egui::Context> {
+            None
+        }
+
+        fn window(&self, _window_id: WindowId) -> Option<Arc<dyn winit::window::Window>> {
+            None
+        }
+
+        fn window_id_from_viewport_id(&self, _id: egui::ViewportId) -> Option<WindowId> {
+            None
+        }
+
+        fn viewport_ids(&self) -> Vec<egui::ViewportId> {
+            Vec::new()
+        }
+
+        fn user_event_sender(&self) -> UserEventSender {
+            UserEventSender::new(std::sync::mpsc::channel().0, || {})
+        }
+
+        fn frame_rate_limit(&self) -> Option<f32> {
+            self.frame_rate_limit
+        }
+
+        fn control_flow_override(&self) -> Option<ControlFlow> {
+            self.control_flow_override
+        }
+
+        fn exit_code(&self) -> i32 {
+            0
+        }
+
+        fn save(&mut self) {}
+
+        fn save_and_destroy(&mut self) {}
+
+        fn run_ui_and_paint(
+            &mut self,
+            _event_loop: &dyn ActiveEventLoop,
+            _window_id: WindowId,
+        ) -> Result<EventResult> {
+            unreachable!("The tests call FakeApp::paint instead")
+        }
+
+        fn suspended(&mut self, _event_loop: &dyn ActiveEventLoop) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+
+        fn resumed(&mut self, _event_loop: &dyn ActiveEventLoop) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+
+        fn device_event(
+            &mut self,
+            _event_loop: &dyn ActiveEventLoop,
+            _device_id: Option<winit::event::DeviceId>,
+            _event: winit::event::DeviceEvent,
+        ) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+
+        fn window_event(
+            &mut self,
+            _event_loop: &dyn ActiveEventLoop,
+            _window_id: WindowId,
+            _event: winit::event::WindowEvent,
+        ) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+
+        fn try_recv_user_event(&mut self) -> Option<UserEvent> {
+            None
+        }
+
+        #[cfg(feature = "accesskit")]
+        fn on_accesskit_event(&mut self, _event: accesskit_winit::Event) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+
+        #[cfg(feature = "tray_icon")]
+        fn on_tray_event(&mut self, _id: u32) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+
+        fn on_user_event(&mut self, _event: Box<dyn std::any::Any + Send>) -> Result<EventResult> {
+            Ok(EventResult::Wait)
+        }
+    }
+
+    /// Simulate one second of an event loop that wakes up every millisecond,
+    /// and return how many frames were painted.
+    fn frames_painted_in_one_second(
+        frame_rate_limit: Option<f32>,
+        repaint_after: Duration,
+    ) -> usize {
+        let app = FakeApp {
+            frame_rate_limit,
+            repaint_after,
+            frames_painted: 0,
+            control_flow_override: None,
+        };
+        let mut wrapper = WinitAppWrapper::new(app, true);
+        let window_id = WindowId::from_raw(1);
+
+        let start = Instant::now();
+        let end = start + Duration::from_secs(1);
+        wrapper.schedule_repaint(EventResult::RepaintNow(window_id), start);
+
+        let mut now = start;
+        while now < end {
+            for window_id in wrapper.take_due_repaints(now) {
+                wrapper.on_frame_start(window_id, now);
+                let event_result = wrapper.winit_app.paint(window_id, now);
+                wrapper.schedule_repaint(event_result, now);
+            }
+            now += Duration::from_millis(1);
+        }
+
+        wrapper.winit_app.frames_painted
+    }
+
+    #[test]
+    fn continuous_repaints_are_unlimited_by_default() {
+        let num_frames = frames_painted_in_one_second(None, Duration::ZERO);
+        assert!(
+            900 <= num_frames,
+            "Painted {num_frames} frames in one second"
+        );
+    }
+
+    #[test]
+    fn frame_rate_limit_caps_continuous_repaints() {
+        let num_frames = frames_painted_in_one_second(Some(10.0), Duration::ZERO);
+        assert!(
+            (9..=12).contains(&num_frames),
+            "Painted {num_frames} frames in one second"
+        );
+    }
+
+    #[test]
+    fn request_repaint_after_wins_over_frame_rate_limit() {
+        let num_frames = frames_painted_in_one_second(Some(10.0), Duration::from_millis(20));
+        assert!(
+            (45..=51).contains(&num_frames),
+            "Painted {num_frames} frames in one second"
+        );
+    }
+
+    #[test]
+    fn frame_rate_limit_does_not_hasten_repaints() {
+        let num_frames = frames_painted_in_one_second(Some(10.0), Duration::from_millis(500));
+        assert_eq!(num_frames, 2);
+
+        let min_frame_time = Duration::from_secs_f32(1.0 / 10.0);
+        let last_frame_time = Instant::now();
+        let later = last_frame_time + Duration::from_secs(5);
+        assert_eq!(
+            earliest_repaint_time(later, last_frame_time, min_frame_time),
+            later
+        );
+    }
+
+    #[test]
+    fn next_repaint_time_ignores_control_flow_override() {
+        let app = FakeApp {
+            frame_rate_limit: None,
+            repaint_after: Duration::ZERO,
+            frames_painted: 0,
+            control_flow_override: Some(ControlFlow::Poll),
+        };
+        let mut wrapper = WinitAppWrapper::new(app, true);
+        let now = Instant::now();
+        assert_eq!(wrapper.next_repaint_time(), None);
+
+        let soon = now + Duration::from_millis(20);
+        let later = now + Duration::from_millis(500);
+        wrapper.schedule_repaint(EventResult::RepaintAt(WindowId::from_raw(1), later), now);
+        wrapper.schedule_repaint(EventResult::RepaintAt(WindowId::from_raw(2), soon), now);
+        assert_eq!(wrapper.next_repaint_time(), Some(soon));
+    }
 }
@@ -0,0 +1,126 @@
+//! Where `eframe` stores its state on native.
+
+use std::path::PathBuf;
+
+/// The folder where `eframe` will store its state.
+///
+/// The given `app_id` is either the
+/// [`egui::ViewportBuilder::app_id`] of [`crate::NativeOptions::viewport`]
+/// or the title argument to [`crate::run_native`].
+///
+/// This is available without the `persistence` feature,
+/// e.g. for apps that bring their own serializer.
+///
+/// The path follows the platform conventions:
+/// * Linux:   `$XDG_DATA_HOME/APP_ID`, or `/home/UserName/.local/share/APP_ID` if that is not set
+/// * macOS:   `/Users/UserName/Library/Application Support/APP_ID`
+/// * Windows: `C:\Users\UserName\AppData\Roaming\APP_ID\data`
+pub fn storage_dir(app_id: &str) -> Option<PathBuf> {
+    use egui::os::OperatingSystem as OS;
+    use std::env::var_os;
+    match OS::from_target_os() {
+        OS::Nix => var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|p| p.is_absolute())
+            .or_else(|| home::home_dir().map(|p| p.join(".local").join("share")))
+            .map(|p| {
+                p.join(
+                    app_id
+                        .to_lowercase()
+                        .replace(|c: char| c.is_ascii_whitespace(), ""),
+                )
+            }),
+        OS::Mac => home::home_dir().map(|p| {
+            p.join("Library")
+                .join("Application Support")
+                .join(app_id.replace(|c: char| c.is_ascii_whitespace(), "-"))
+        }),
+        OS::Windows => roaming_appdata().map(|p| p.join(app_id).join("data")),
+        OS::Unknown | OS::Android | OS::IOS => None,
+    }
+}
+
+/// The name of the file inside [`storage_dir`] where the `persistence` feature keeps the app state.
+pub(crate) const STORAGE_FILE_NAME: &str = "app.ron";
+
+/// The file where `eframe` will store its state.
+///
+/// This is the `app.ron` file inside [`storage_dir`],
+/// e.g. for an "open config file" or "reset to defaults" button.
+/// The file may not exist yet.
+pub fn storage_file(app_id: &str) -> Option<PathBuf> {
+    storage_dir(app_id).map(|dir| dir.join(STORAGE_FILE_NAME))
+}
+
+// Adapted from
+// https://github.com/rust-lang/cargo/blob/6e11c77384989726bb4f412a0e23b59c27222c34/crates/home/src/windows.rs#L19-L37
+#[cfg(all(windows, not(target_vendor = "uwp")))]
+#[expect(unsafe_code)]
+fn roaming_appdata() -> Option<PathBuf> {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt as _;
+    use std::ptr;
+    use std::slice;
+
+    use windows_sys::Win32::Foundation::S_OK;
+    use windows_sys::Win32::System::Com::CoTaskMemFree;
+    use windows_sys::Win32::UI::Shell::{
+        FOLDERID_RoamingAppData, KF_FLAG_DONT_VERIFY, SHGetKnownFolderPath,
+    };
+
+    unsafe extern "C" {
+        fn wcslen(buf: *const u16) -> usize;
+    }
+    let mut path_raw = ptr::null_mut();
+
+    // SAFETY: SHGetKnownFolderPath allocates for us, we don't pass any pointers to it.
+    // See https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath
+    let result = unsafe {
+        SHGetKnownFolderPath(
+            &FOLDERID_RoamingAppData,
+            KF_FLAG_DONT_VERIFY as u32,
+            std::ptr::null_mut(),
+            &mut path_raw,
+        )
+    };
+
+    let path = if result == S_OK {
+        // SAFETY: SHGetKnownFolderPath indicated success and is supposed to allocate a null-terminated string for us.
+        let path_slice = unsafe { slice::from_raw_parts(path_raw, wcslen(path_raw)) };
+        Some(PathBuf::from(OsString::from_wide(path_slice)))
+    } else {
+        None
+    };
+
+    // SAFETY:
+    // This memory got allocated by SHGetKnownFolderPath, we didn't touch anything in the process.
+    // A null ptr is a no-op for `CoTaskMemFree`, so in case this failed we're still good.
+    // https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemfree
+    unsafe { CoTaskMemFree(path_raw.cast()) };
+
+    path
+}
+
+#[cfg(any(not(windows), target_vendor = "uwp"))]
+fn roaming_appdata() -> Option<PathBuf> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directories_storage_dir(app_id: &str) -> Option<PathBuf> {
+        directories::ProjectDirs::from("", "", app_id)
+            .map(|proj_dirs| proj_dirs.data_dir().to_path_buf())
+    }
+
+    #[test]
+    fn storage_path_matches_directories() {
+        for app_id in [
+            "MyApp", "My App", "my_app", "my-app", "My.App", "my/app", "my:app", r"my\app",
+        ] {
+            assert_eq!(directories_storage_dir(app_id), storage_dir(app_id));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! The system tray icon, see [`crate::NativeOptions::tray_icon`].
+
+use std::sync::Arc;
+
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+
+use crate::{NativeOptions, TrayMenuItem};
+
+/// Keeps the tray icon alive.
+///
+/// The tray icon is removed when this is dropped.
+pub struct TrayHandle {
+    // On Linux the tray icon lives on its own gtk thread instead.
+    #[cfg(not(target_os = "linux"))]
+    _tray_icon: tray_icon::TrayIcon,
+}
+
+/// Show the tray icon from the [`NativeOptions`], if any.
+///
+/// `on_menu_item` is called (from any thread) with the id of each clicked [`TrayMenuItem::Item`].
+///
+/// Must be called on the main thread, after the event loop has started.
+pub fn create_tray(
+    native_options: &NativeOptions,
+    on_menu_item: impl Fn(u32) + Send + Sync + 'static,
+) -> Option<TrayHandle> {
+    let icon = native_options.tray_icon.clone()?;
+    let menu_items = native_options.tray_menu.clone();
+
+    MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+        if let Ok(id) = event.id().0.parse() {
+            on_menu_item(id);
+        }
+    }));
+
+    #[cfg(target_os = "linux")]
+    {
+        // tray-icon needs a running gtk event loop on the thread that creates the icon:
+        let spawn_result = std::thread::Builder::new()
+            .name("eframe_tray_icon".to_owned())
+            .spawn(move || {
+                if let Err(err) = gtk::init() {
+                    log::warn!("Failed to initialize gtk for the tray icon: {err}");
+                    return;
+                }
+                let Some(_tray_icon) = build_tray_icon(&icon, menu_items.as_deref()) else {
+                    return;
+                };
+                gtk::main();
+            });
+        if let Err(err) = spawn_result {
+            log::warn!("Failed to spawn tray icon thread: {err}");
+            return None;
+        }
+        Some(TrayHandle {})
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let tray_icon = build_tray_icon(&icon, menu_items.as_deref())?;
+        Some(TrayHandle {
+            _tray_icon: tray_icon,
+        })
+    }
+}
+
+fn build_tray_icon(
+    icon: &Arc<egui::IconData>,
+    menu_items: Option<&[TrayMenuItem]>,
+) -> Option<tray_icon::TrayIcon> {
+    profiling::function_scope!();
+
+    let icon = match tray_icon::Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height) {
+        Ok(icon) => icon,
+        Err(err) => {
+            log::warn!("Invalid tray icon: {err}");
+            return None;
+        }
+    };
+
+    let mut builder = tray_icon::TrayIconBuilder::new().with_icon(icon);
+    if let Some(menu_items) = menu_items {
+        builder = builder.with_menu(Box::new(build_menu(menu_items)));
+    }
+
+    match builder.build() {
+        Ok(tray_icon) => Some(tray_icon),
+        Err(err) => {
+            log::warn!("Failed to create tray icon: {err}");
+            None
+        }
+    }
+}
+
+fn build_menu(menu_items: &[TrayMenuItem]) -> Menu {
+    let menu = Menu::new();
+    for menu_item in menu_items {
+        let result = match menu_item {
+            TrayMenuItem::Label(label) => menu.append(&MenuItem::new(label, false, None)),
+            TrayMenuItem::Separator => menu.append(&PredefinedMenuItem::separator()),
+            TrayMenuItem::Item { label, id } => {
+                menu.append(&MenuItem::with_id(id.to_string(), label, true, None))
+            }
+        };
+        if let Err(err) = result {
+            log::warn!("Failed to add tray menu item {menu_item:?}: {err}");
+        }
+    }
+    menu
+}
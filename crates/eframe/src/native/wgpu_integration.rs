@@ -31,7 +31,8 @@ use egui_winit::accesskit_winit;
 use winit_integration::UserEvent;
 
 use crate::{
-    App, AppCreator, CreationContext, NativeOptions, Result, Storage,
+    App, AppCreator, CreationContext, NativeOptions, Renderer, Result, Storage,
+    ViewportBuilderHook,
     native::{epi_integration::EpiIntegration, winit_integration::EventResult},
 };
 
@@ -50,8 +51,14 @@ pub struct WgpuWinitApp<'app> {
     /// Set at initialization, then taken and set to `None` in `init_run_state`.
     app_creator: Option<AppCreator<'app>>,
 
+    /// An existing context to use instead of creating a new one, taken on first resume.
+    egui_ctx: Option<egui::Context>,
+
     /// Set when we are actually up and running.
     running: Option<WgpuWinitRunning<'app>>,
+
+    #[cfg(feature = "tray_icon")]
+    _tray: Option<super::tray::TrayHandle>,
 }
 
 /// State that is initialized when the application is first starts running via
@@ -75,13 +82,93 @@ struct WgpuWinitRunning<'app> {
 pub struct SharedState {
     egui_ctx: egui::Context,
     viewports: Viewports,
-    painter: egui_wgpu::winit::Painter,
+
+    /// Behind a mutex so that the [`RenderThread`] can paint with it.
+    painter: Arc<Mutex<egui_wgpu::winit::Painter>>,
+
+    /// See [`NativeOptions::multi_threaded_rendering`].
+    render_thread: Option<RenderThread>,
+
     viewport_from_window: HashMap<WindowId, ViewportId>,
     focused_viewport: Option<ViewportId>,
+
+    /// See [`NativeOptions::viewport_builder_hook`].
+    viewport_builder_hook: Option<ViewportBuilderHook>,
 }
 
 pub type Viewports = ViewportIdMap<Viewport>;
 
+/// Everything needed to paint one frame of one viewport.
+struct RenderJob {
+    viewport_id: ViewportId,
+    pixels_per_point: f32,
+    clear_color: [f32; 4],
+    clipped_primitives: Vec<egui::ClippedPrimitive>,
+    textures_delta: egui::TexturesDelta,
+    screenshot_commands: Vec<egui::UserData>,
+}
+
+impl RenderJob {
+    /// Returns the time spent waiting for vsync, in seconds.
+    fn paint(self, painter: &mut egui_wgpu::winit::Painter) -> f32 {
+        painter.paint_and_update_textures(
+            self.viewport_id,
+            self.pixels_per_point,
+            self.clear_color,
+            &self.clipped_primitives,
+            &self.textures_delta,
+            self.screenshot_commands,
+        )
+    }
+}
+
+/// Paints frames on a separate thread, see [`NativeOptions::multi_threaded_rendering`].
+///
+/// Jobs are painted in the order they are submitted,
+/// so texture updates are always applied before they are used.
+struct RenderThread {
+    /// Dropped first on shutdown, which lets the thread finish the jobs in flight and exit.
+    job_sender: Option<mpsc::SyncSender<RenderJob>>,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl RenderThread {
+    fn spawn(painter: Arc<Mutex<egui_wgpu::winit::Painter>>) -> std::io::Result<Self> {
+        // One job being painted, and at most one more waiting:
+        let (job_sender, job_receiver) = mpsc::sync_channel::<RenderJob>(1);
+        let join_handle = std::thread::Builder::new()
+            .name("eframe_render".to_owned())
+            .spawn(move || {
+                for job in job_receiver {
+                    profiling::scope!("render_thread_paint");
+                    job.paint(&mut painter.lock());
+                }
+            })?;
+        Ok(Self {
+            job_sender: Some(job_sender),
+            join_handle: Some(join_handle),
+        })
+    }
+
+    /// Must not be called while holding the painter lock, or we could deadlock.
+    fn submit(&self, job: RenderJob) {
+        if let Some(job_sender) = &self.job_sender {
+            if job_sender.send(job).is_err() {
+                log::warn!("The render thread has stopped - dropping frame");
+            }
+        }
+    }
+}
+
+impl Drop for RenderThread {
+    fn drop(&mut self) {
+        self.job_sender = None;
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.join().ok();
+        }
+    }
+}
+
 pub struct Viewport {
     ids: ViewportIdPair,
     class: ViewportClass,
@@ -128,9 +215,46 @@ impl<'app> WgpuWinitApp<'app> {
             native_options,
             running: None,
             app_creator: Some(app_creator),
+            egui_ctx: None,
+            #[cfg(feature = "tray_icon")]
+            _tray: None,
         }
     }
 
+    /// Use this context instead of creating a new one.
+    ///
+    /// See [`crate::create_native_with_context`].
+    pub fn with_egui_ctx(mut self, egui_ctx: Option<egui::Context>) -> Self {
+        self.egui_ctx = egui_ctx;
+        self
+    }
+
+    /// See [`crate::NativeOptions::save_on_os_signal`].
+    #[cfg(feature = "os_signals")]
+    fn install_os_signal_handler(&self) {
+        let event_loop_proxy = self.repaint_proxy.clone();
+        let user_events_sender = self.user_events_sender.clone();
+        winit_integration::install_os_signal_handler(move || {
+            if user_events_sender.send(UserEvent::OsSignal).is_err() {
+                // The event loop is already gone, so there is nothing left to save.
+                std::process::exit(1);
+            }
+            event_loop_proxy.lock().wake_up();
+        });
+    }
+
+    #[cfg(feature = "tray_icon")]
+    fn create_tray(&mut self) {
+        let event_loop_proxy = self.repaint_proxy.clone();
+        let user_events_sender = self.user_events_sender.clone();
+        self._tray = super::tray::create_tray(&self.native_options, move |id| {
+            user_events_sender
+                .send(UserEvent::TrayMenuActivated(id))
+                .ok();
+            event_loop_proxy.lock().wake_up();
+        });
+    }
+
     /// Create a window for all viewports lacking one.
     fn initialized_all_windows(&mut self, event_loop: &dyn ActiveEventLoop) {
         let Some(running) = &mut self.running else {
@@ -144,12 +268,13 @@ impl<'app> WgpuWinitApp<'app> {
             ..
         } = &mut *shared;
 
+        let mut painter = painter.lock();
         for viewport in viewports.values_mut() {
             viewport.initialize_window(
                 event_loop,
                 &running.integration.egui_ctx,
                 viewport_from_window,
-                painter,
+                &mut painter,
             );
         }
     }
@@ -161,8 +286,10 @@ impl<'app> WgpuWinitApp<'app> {
             viewports,
             viewport_from_window,
             painter,
+            viewport_builder_hook,
             ..
         } = &mut *running.shared.borrow_mut();
+        let mut painter = painter.lock();
 
         initialize_or_update_viewport(
             viewports,
@@ -170,9 +297,10 @@ impl<'app> WgpuWinitApp<'app> {
             ViewportClass::Root,
             self.native_options.viewport.clone(),
             None,
-            painter,
+            &mut painter,
+            viewport_builder_hook.as_ref(),
         )
-        .initialize_window(event_loop, egui_ctx, viewport_from_window, painter);
+        .initialize_window(event_loop, egui_ctx, viewport_from_window, &mut painter);
     }
 
     #[cfg(target_os = "android")]
@@ -180,7 +308,7 @@ impl<'app> WgpuWinitApp<'app> {
         if let Some(running) = &mut self.running {
             let mut shared = running.shared.borrow_mut();
             shared.viewports.remove(&ViewportId::ROOT);
-            pollster::block_on(shared.painter.set_window(ViewportId::ROOT, None))?;
+            pollster::block_on(shared.painter.lock().set_window(ViewportId::ROOT, None))?;
         }
         Ok(())
     }
@@ -215,9 +343,9 @@ impl<'app> WgpuWinitApp<'app> {
 
         let wgpu_render_state = painter.render_state();
 
-        let integration = EpiIntegration::new(
+        let mut integration = EpiIntegration::new(
             egui_ctx.clone(),
-            window.as_ref(),
+            Some(window.as_ref()),
             &self.app_name,
             &self.native_options,
             storage,
@@ -228,6 +356,8 @@ impl<'app> WgpuWinitApp<'app> {
             wgpu_render_state.clone(),
         );
 
+        integration.frame.user_event_sender = Some(self.user_event_sender());
+
         {
             let event_loop_proxy = self.repaint_proxy.clone();
             let user_events_sender = self.user_events_sender.clone();
@@ -270,6 +400,7 @@ impl<'app> WgpuWinitApp<'app> {
             egui_ctx: egui_ctx.clone(),
             integration_info: integration.frame.info().clone(),
             storage: integration.frame.storage(),
+            renderer: Renderer::Wgpu,
             #[cfg(feature = "glow")]
             gl: None,
             #[cfg(feature = "glow")]
@@ -278,11 +409,14 @@ impl<'app> WgpuWinitApp<'app> {
             raw_display_handle: window.display_handle().map(|h| h.as_raw()),
             raw_window_handle: window.window_handle().map(|h| h.as_raw()),
         };
-        let app = {
+        let mut app = {
             profiling::scope!("user_app_creator");
             app_creator(&cc).map_err(crate::Error::AppCreation)?
         };
 
+        integration.report_storage_load_errors(app.as_mut());
+        integration.migrate_storage(app.as_mut());
+
         let mut viewport_from_window = HashMap::default();
         viewport_from_window.insert(window.id(), ViewportId::ROOT);
 
@@ -305,12 +439,25 @@ impl<'app> WgpuWinitApp<'app> {
             },
         );
 
+        let painter = Arc::new(Mutex::new(painter));
+        let render_thread = if self.native_options.multi_threaded_rendering {
+            RenderThread::spawn(painter.clone())
+                .inspect_err(|err| {
+                    log::warn!("Failed to spawn render thread, painting on the main thread: {err}");
+                })
+                .ok()
+        } else {
+            None
+        };
+
         let shared = Rc::new(RefCell::new(SharedState {
             egui_ctx,
             viewport_from_window,
             viewports,
             painter,
+            render_thread,
             focused_viewport: Some(ViewportId::ROOT),
+            viewport_builder_hook: self.native_options.viewport_builder_hook.clone(),
         }));
 
         {
@@ -353,6 +500,20 @@ impl WinitApp for WgpuWinitApp<'_> {
             .flatten()
     }
 
+    fn frame_rate_limit(&self) -> Option<f32> {
+        self.native_options.frame_rate_limit
+    }
+
+    fn control_flow_override(&self) -> Option<winit::event_loop::ControlFlow> {
+        self.native_options.control_flow_override
+    }
+
+    fn exit_code(&self) -> i32 {
+        self.running
+            .as_ref()
+            .map_or(0, |running| running.integration.frame.exit_code)
+    }
+
     fn window_id_from_viewport_id(&self, id: ViewportId) -> Option<WindowId> {
         Some(
             self.running
@@ -367,6 +528,19 @@ impl WinitApp for WgpuWinitApp<'_> {
         )
     }
 
+    fn viewport_ids(&self) -> Vec<ViewportId> {
+        self.running.as_ref().map_or_else(Vec::new, |running| {
+            running.shared.borrow().viewports.keys().copied().collect()
+        })
+    }
+
+    fn user_event_sender(&self) -> winit_integration::UserEventSender {
+        let event_loop_proxy = self.repaint_proxy.clone();
+        winit_integration::UserEventSender::new(self.user_events_sender.clone(), move || {
+            event_loop_proxy.lock().wake_up();
+        })
+    }
+
     fn save(&mut self) {
         log::debug!("WinitApp::save called");
         if let Some(running) = self.running.as_mut() {
@@ -397,11 +571,23 @@ impl WinitApp for WgpuWinitApp<'_> {
     fn resumed(&mut self, event_loop: &dyn ActiveEventLoop) -> crate::Result<EventResult> {
         log::debug!("Event::Resumed");
 
-        let running = if let Some(running) = &self.running {
-            #[cfg(target_os = "android")]
+        #[cfg(target_os = "android")]
+        if let Some(running) = &self.running {
             self.recreate_window(event_loop, running);
+        }
+
+        let running = if let Some(running) = &mut self.running {
+            // Not the first resume event.
+            running.app.on_resume(&running.integration.egui_ctx);
             running
         } else {
+            #[cfg(feature = "tray_icon")]
+            self.create_tray();
+            #[cfg(feature = "os_signals")]
+            if self.native_options.save_on_os_signal {
+                self.install_os_signal_handler();
+            }
+
             let storage = if let Some(file) = &self.native_options.persistence_path {
                 epi_integration::create_storage_with_file(file)
             } else {
@@ -413,7 +599,8 @@ impl WinitApp for WgpuWinitApp<'_> {
                         .unwrap_or(&self.app_name),
                 )
             };
-            let egui_ctx = winit_integration::create_egui_context(storage.as_deref());
+            let egui_ctx =
+                winit_integration::create_egui_context(storage.as_deref(), self.egui_ctx.take());
             let (window, builder) = create_window(
                 &egui_ctx,
                 event_loop,
@@ -432,6 +619,9 @@ impl WinitApp for WgpuWinitApp<'_> {
     }
 
     fn suspended(&mut self, _: &dyn ActiveEventLoop) -> crate::Result<EventResult> {
+        if let Some(running) = &mut self.running {
+            running.app.on_suspend(&running.integration.egui_ctx);
+        }
         #[cfg(target_os = "android")]
         self.drop_window()?;
         Ok(EventResult::Save)
@@ -509,6 +699,25 @@ impl WinitApp for WgpuWinitApp<'_> {
 
         Ok(EventResult::Wait)
     }
+
+    #[cfg(feature = "tray_icon")]
+    fn on_tray_event(&mut self, id: u32) -> crate::Result<EventResult> {
+        if let Some(running) = &mut self.running {
+            running.app.on_tray_event(&running.integration.egui_ctx, id);
+            running.integration.egui_ctx.request_repaint();
+        }
+        Ok(EventResult::Wait)
+    }
+
+    fn on_user_event(&mut self, event: Box<dyn std::any::Any + Send>) -> Result<EventResult> {
+        if let Some(running) = &mut self.running {
+            running
+                .app
+                .on_user_event(&running.integration.egui_ctx, event.as_ref());
+            running.integration.egui_ctx.request_repaint();
+        }
+        Ok(EventResult::Wait)
+    }
 }
 
 impl WgpuWinitRunning<'_> {
@@ -537,7 +746,8 @@ impl WgpuWinitRunning<'_> {
         self.app.on_exit();
 
         let mut shared = self.shared.borrow_mut();
-        shared.painter.destroy();
+        shared.render_thread = None; // finish painting before destroying the painter
+        shared.painter.lock().destroy();
     }
 
     /// This is called both for the root viewport, and all deferred viewports
@@ -570,7 +780,10 @@ impl WgpuWinitRunning<'_> {
             let mut shared_lock = shared.borrow_mut();
 
             let SharedState {
-                viewports, painter, ..
+                viewports,
+                painter,
+                render_thread,
+                ..
             } = &mut *shared_lock;
 
             if viewport_id != ViewportId::ROOT {
@@ -609,7 +822,16 @@ impl WgpuWinitRunning<'_> {
             };
             egui_winit::update_viewport_info(info, &integration.egui_ctx, window.as_ref(), false);
 
-            {
+            // With a render thread, the painter may still be busy with the previous frame.
+            // Don't wait for it: we can build this frame in the meantime,
+            // and anything we skip here is done next frame instead.
+            let mut painter = if render_thread.is_some() {
+                painter.try_lock()
+            } else {
+                Some(painter.lock())
+            };
+
+            if let Some(painter) = &mut painter {
                 profiling::scope!("set_window");
                 pollster::block_on(painter.set_window(viewport_id, Some(window.clone())))?;
             }
@@ -619,7 +841,7 @@ impl WgpuWinitRunning<'_> {
             };
             let mut raw_input = egui_winit.take_egui_input(window.as_ref());
 
-            integration.pre_update();
+            integration.pre_update(viewport_id, window.as_ref());
 
             raw_input.time = Some(integration.beginning.elapsed().as_secs_f64());
             raw_input.viewports = viewports
@@ -627,7 +849,9 @@ impl WgpuWinitRunning<'_> {
                 .map(|(id, viewport)| (*id, viewport.info.clone()))
                 .collect();
 
-            painter.handle_screenshots(&mut raw_input.events);
+            if let Some(painter) = &painter {
+                painter.handle_screenshots(&mut raw_input.events);
+            }
 
             (viewport_ui_cb, raw_input)
         };
@@ -646,9 +870,12 @@ impl WgpuWinitRunning<'_> {
             egui_ctx,
             viewports,
             painter,
+            render_thread,
             viewport_from_window,
+            viewport_builder_hook,
             ..
         } = &mut *shared_mut;
+        let mut painter = painter.lock();
 
         let FullOutput {
             platform_output,
@@ -658,7 +885,12 @@ impl WgpuWinitRunning<'_> {
             viewport_output,
         } = full_output;
 
-        remove_viewports_not_in(viewports, painter, viewport_from_window, &viewport_output);
+        remove_viewports_not_in(
+            viewports,
+            &mut painter,
+            viewport_from_window,
+            &viewport_output,
+        );
 
         let Some(viewport) = viewports.get_mut(&viewport_id) else {
             return Ok(EventResult::Wait);
@@ -677,7 +909,9 @@ impl WgpuWinitRunning<'_> {
 
         egui_winit.handle_platform_output(window.as_ref(), platform_output);
 
+        let tessellation_start = Instant::now();
         let clipped_primitives = egui_ctx.tessellate(shapes, pixels_per_point);
+        let tessellation_time = tessellation_start.elapsed().as_secs_f32();
 
         let mut screenshot_commands = vec![];
         viewport.actions_requested.retain(|cmd| {
@@ -688,14 +922,32 @@ impl WgpuWinitRunning<'_> {
                 true
             }
         });
-        let vsync_secs = painter.paint_and_update_textures(
+        if viewport_id == ViewportId::ROOT && integration.frame.screenshot_requested.take() {
+            screenshot_commands.push(epi_integration::frame_screenshot_user_data());
+        }
+        let render_job = RenderJob {
             viewport_id,
             pixels_per_point,
-            app.clear_color(&egui_ctx.style().visuals),
-            &clipped_primitives,
-            &textures_delta,
+            clear_color: app.clear_color(&egui_ctx.style().visuals),
+            clipped_primitives,
+            textures_delta,
             screenshot_commands,
-        );
+        };
+
+        // The render thread gets the job once we are done with the painter below.
+        let (vsync_secs, paint_time, render_job) = if render_thread.is_some() {
+            (0.0, None, Some(render_job))
+        } else {
+            let paint_start = Instant::now();
+            let vsync_secs = render_job.paint(&mut painter);
+            let paint_time = paint_start.elapsed().as_secs_f32() - vsync_secs;
+            (vsync_secs, Some(paint_time), None)
+        };
+
+        if viewport_id == ViewportId::ROOT {
+            integration.frame.info.tessellation_time = Some(tessellation_time);
+            integration.frame.info.paint_time = paint_time;
+        }
 
         for action in viewport.actions_requested.drain() {
             match action {
@@ -722,7 +974,7 @@ impl WgpuWinitRunning<'_> {
             }
         }
 
-        integration.post_rendering(window.as_ref());
+        integration.post_rendering(app.as_mut(), viewport_id, window.as_ref());
 
         let active_viewports_ids: ViewportIdSet = viewport_output.keys().copied().collect();
 
@@ -730,8 +982,9 @@ impl WgpuWinitRunning<'_> {
             &integration.egui_ctx,
             &viewport_output,
             viewports,
-            painter,
+            &mut painter,
             viewport_from_window,
+            viewport_builder_hook.as_ref(),
         );
 
         // Prune dead viewports:
@@ -739,6 +992,11 @@ impl WgpuWinitRunning<'_> {
         viewport_from_window.retain(|_, id| active_viewports_ids.contains(id));
         painter.gc_viewports(&active_viewports_ids);
 
+        drop(painter);
+        if let (Some(render_thread), Some(render_job)) = (render_thread.as_ref(), render_job) {
+            render_thread.submit(render_job);
+        }
+
         let window = viewport_from_window
             .get(&window_id)
             .and_then(|id| viewports.get(id))
@@ -771,8 +1029,8 @@ impl WgpuWinitRunning<'_> {
     ) -> EventResult {
         let Self {
             integration,
+            app,
             shared,
-            ..
         } = self;
         let mut shared = shared.borrow_mut();
 
@@ -808,7 +1066,10 @@ impl WgpuWinitRunning<'_> {
                         NonZeroU32::new(physical_size.height),
                     ) {
                         repaint_asap = true;
-                        shared.painter.on_window_resized(viewport_id, width, height);
+                        shared
+                            .painter
+                            .lock()
+                            .on_window_resized(viewport_id, width, height);
                     }
                 }
             }
@@ -844,6 +1105,8 @@ impl WgpuWinitRunning<'_> {
             .and_then(|viewport_id| {
                 shared.viewports.get_mut(&viewport_id).and_then(|viewport| {
                     Some(integration.on_window_event(
+                        app.as_mut(),
+                        viewport_id,
                         viewport.window.as_deref()?,
                         viewport.egui_winit.as_mut()?,
                         event,
@@ -928,6 +1191,7 @@ fn create_window(
         event_loop,
         native_options,
         window_settings,
+        epi_integration::load_window_monitor_name(storage).as_deref(),
     )
     .with_visible(false); // Start hidden until we render the first frame to fix white flash on startup (https://github.com/emilk/egui/pull/3631)
 
@@ -955,8 +1219,10 @@ fn render_immediate_viewport(
             viewports,
             painter,
             viewport_from_window,
+            viewport_builder_hook,
             ..
         } = &mut *shared.borrow_mut();
+        let mut painter = painter.lock();
 
         let viewport = initialize_or_update_viewport(
             viewports,
@@ -964,11 +1230,17 @@ fn render_immediate_viewport(
             ViewportClass::Immediate,
             builder,
             None,
-            painter,
+            &mut painter,
+            viewport_builder_hook.as_ref(),
         );
         if viewport.window.is_none() {
             event_loop_context::with_current_event_loop(|event_loop| {
-                viewport.initialize_window(event_loop, egui_ctx, viewport_from_window, painter);
+                viewport.initialize_window(
+                    event_loop,
+                    egui_ctx,
+                    viewport_from_window,
+                    &mut painter,
+                );
             });
         }
 
@@ -1008,9 +1280,12 @@ fn render_immediate_viewport(
     let SharedState {
         viewports,
         painter,
+        render_thread,
         viewport_from_window,
+        viewport_builder_hook,
         ..
     } = &mut *shared_mut;
+    let mut painter = painter.lock();
 
     let Some(viewport) = viewports.get_mut(&ids.this) else {
         return;
@@ -1030,15 +1305,20 @@ fn render_immediate_viewport(
         }
     }
 
-    let clipped_primitives = egui_ctx.tessellate(shapes, pixels_per_point);
-    painter.paint_and_update_textures(
-        ids.this,
+    let render_job = RenderJob {
+        viewport_id: ids.this,
         pixels_per_point,
-        [0.0, 0.0, 0.0, 0.0],
-        &clipped_primitives,
-        &textures_delta,
-        vec![],
-    );
+        clear_color: [0.0, 0.0, 0.0, 0.0],
+        clipped_primitives: egui_ctx.tessellate(shapes, pixels_per_point),
+        textures_delta,
+        screenshot_commands: vec![],
+    };
+    let render_job = if render_thread.is_some() {
+        Some(render_job)
+    } else {
+        render_job.paint(&mut painter);
+        None
+    };
 
     egui_winit.handle_platform_output(window.as_ref(), platform_output);
 
@@ -1046,9 +1326,15 @@ fn render_immediate_viewport(
         &egui_ctx,
         &viewport_output,
         viewports,
-        painter,
+        &mut painter,
         viewport_from_window,
+        viewport_builder_hook.as_ref(),
     );
+
+    drop(painter);
+    if let (Some(render_thread), Some(render_job)) = (render_thread.as_ref(), render_job) {
+        render_thread.submit(render_job);
+    }
 }
 
 pub(crate) fn remove_viewports_not_in(
@@ -1072,6 +1358,7 @@ fn handle_viewport_output(
     viewports: &mut ViewportIdMap<Viewport>,
     painter: &mut egui_wgpu::winit::Painter,
     viewport_from_window: &mut HashMap<WindowId, ViewportId>,
+    viewport_builder_hook: Option<&ViewportBuilderHook>,
 ) {
     for (
         viewport_id,
@@ -1087,8 +1374,15 @@ fn handle_viewport_output(
     {
         let ids = ViewportIdPair::from_self_and_parent(viewport_id, parent);
 
-        let viewport =
-            initialize_or_update_viewport(viewports, ids, class, builder, viewport_ui_cb, painter);
+        let viewport = initialize_or_update_viewport(
+            viewports,
+            ids,
+            class,
+            builder,
+            viewport_ui_cb,
+            painter,
+            viewport_builder_hook,
+        );
 
         if let Some(window) = viewport.window.as_ref() {
             let old_inner_size = window.surface_size();
@@ -1128,9 +1422,14 @@ fn initialize_or_update_viewport<'a>(
     mut builder: ViewportBuilder,
     viewport_ui_cb: Option<Arc<dyn Fn(&egui::Context) + Send + Sync>>,
     painter: &mut egui_wgpu::winit::Painter,
+    viewport_builder_hook: Option<&ViewportBuilderHook>,
 ) -> &'a mut Viewport {
     profiling::function_scope!();
 
+    if let Some(hook) = viewport_builder_hook {
+        builder = hook(ids.this, builder);
+    }
+
     if builder.icon.is_none() {
         // Inherit icon from parent
         builder.icon = viewports
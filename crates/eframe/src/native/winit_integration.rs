@@ -10,7 +10,13 @@ use egui::ViewportId;
 use egui_winit::accesskit_winit;
 
 /// Create an egui context, restoring it from storage if possible.
-pub fn create_egui_context(storage: Option<&dyn crate::Storage>) -> egui::Context {
+///
+/// If `existing` is set, that context is reused instead of creating a new one.
+/// It keeps its own `max_passes`, and its memory unless `storage` has some saved.
+pub fn create_egui_context(
+    storage: Option<&dyn crate::Storage>,
+    existing: Option<egui::Context>,
+) -> egui::Context {
     profiling::function_scope!();
 
     pub const IS_DESKTOP: bool = cfg!(any(
@@ -21,17 +27,25 @@ pub fn create_egui_context(storage: Option<&dyn crate::Storage>) -> egui::Contex
         target_os = "windows",
     ));
 
-    let egui_ctx = egui::Context::default();
+    let is_new = existing.is_none();
+    let egui_ctx = existing.unwrap_or_default();
 
-    egui_ctx.set_embed_viewports(!IS_DESKTOP);
+    // Embedding is the egui default, so a context that has opted out keeps its setting.
+    if egui_ctx.embed_viewports() {
+        egui_ctx.set_embed_viewports(!IS_DESKTOP);
+    }
 
-    egui_ctx.options_mut(|o| {
-        // eframe supports multi-pass (Context::request_discard).
-        o.max_passes = 2.try_into().unwrap();
-    });
+    if is_new {
+        egui_ctx.options_mut(|o| {
+            // eframe supports multi-pass (Context::request_discard).
+            o.max_passes = 2.try_into().unwrap();
+        });
+    }
 
-    let memory = crate::native::epi_integration::load_egui_memory(storage).unwrap_or_default();
-    egui_ctx.memory_mut(|mem| *mem = memory);
+    let memory = crate::native::epi_integration::load_egui_memory(storage);
+    if is_new || memory.is_some() {
+        egui_ctx.memory_mut(|mem| *mem = memory.unwrap_or_default());
+    }
 
     egui_ctx
 }
@@ -54,6 +68,18 @@ pub enum UserEvent {
     /// A request related to [`accesskit`](https://accesskit.dev/).
     #[cfg(feature = "accesskit")]
     AccessKitActionRequest(accesskit_winit::Event),
+
+    /// An item of the [`crate::NativeOptions::tray_menu`] was clicked.
+    #[cfg(feature = "tray_icon")]
+    TrayMenuActivated(u32),
+
+    /// The process was asked to terminate, see [`crate::NativeOptions::save_on_os_signal`].
+    #[cfg(feature = "os_signals")]
+    OsSignal,
+
+    /// An event sent by the app itself with [`UserEventSender::send`],
+    /// to be passed on to [`crate::App::on_user_event`].
+    User(Box<dyn std::any::Any + Send>),
 }
 
 #[cfg(feature = "accesskit")]
@@ -63,6 +89,75 @@ impl From<accesskit_winit::Event> for UserEvent {
     }
 }
 
+/// Sends your own events through the winit event loop, to [`crate::App::on_user_event`].
+///
+/// Get one with [`crate::Frame::event_loop_proxy`],
+/// or with [`crate::EframeWinitApplication::user_event_sender`] when running on your own event loop.
+/// It can be cloned and sent to other threads, e.g. to deliver results of background work.
+#[derive(Clone)]
+pub struct UserEventSender {
+    sender: std::sync::mpsc::Sender<UserEvent>,
+    wake_up: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl UserEventSender {
+    pub(crate) fn new(
+        sender: std::sync::mpsc::Sender<UserEvent>,
+        wake_up: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            sender,
+            wake_up: Arc::new(wake_up),
+        }
+    }
+
+    /// Send an event to [`crate::App::on_user_event`], and wake up the event loop.
+    ///
+    /// The app can recover the type of the event with [`std::any::Any::downcast_ref`].
+    ///
+    /// Does nothing if the event loop has already exited.
+    pub fn send<T: std::any::Any + Send>(&self, event: T) {
+        if self.sender.send(UserEvent::User(Box::new(event))).is_ok() {
+            (self.wake_up)();
+        }
+    }
+}
+
+/// Call `on_signal` (from another thread) when the process is asked to terminate.
+///
+/// See [`crate::NativeOptions::save_on_os_signal`]. Does nothing on platforms without signals.
+///
+/// The OS handler can only be installed once per process,
+/// so each call replaces the `on_signal` of any earlier run of the event loop.
+#[cfg(feature = "os_signals")]
+pub fn install_os_signal_handler(on_signal: impl FnMut() + Send + 'static) {
+    #[cfg(any(unix, windows))]
+    {
+        type OnSignal = Box<dyn FnMut() + Send>;
+        static ON_SIGNAL: parking_lot::Mutex<Option<OnSignal>> = parking_lot::Mutex::new(None);
+        static INSTALL: std::sync::Once = std::sync::Once::new();
+
+        *ON_SIGNAL.lock() = Some(Box::new(on_signal));
+
+        INSTALL.call_once(|| {
+            let result = ctrlc::set_handler(|| {
+                if let Some(on_signal) = ON_SIGNAL.lock().as_mut() {
+                    on_signal();
+                }
+            });
+            if let Err(err) = result {
+                log::warn!("Failed to install a handler for OS signals: {err}");
+            }
+        });
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        _ = on_signal;
+        log::debug!("NativeOptions::save_on_os_signal is not supported on this platform");
+    }
+}
+
 pub trait WinitApp {
     fn egui_ctx(&self) -> Option<&egui::Context>;
 
@@ -70,6 +165,23 @@ pub trait WinitApp {
 
     fn window_id_from_viewport_id(&self, id: ViewportId) -> Option<WindowId>;
 
+    /// All currently open viewports, including the root viewport.
+    ///
+    /// Empty before the app has started running.
+    fn viewport_ids(&self) -> Vec<ViewportId>;
+
+    /// A sender of [`UserEvent::User`] into this app's event loop.
+    fn user_event_sender(&self) -> UserEventSender;
+
+    /// See [`crate::NativeOptions::frame_rate_limit`].
+    fn frame_rate_limit(&self) -> Option<f32>;
+
+    /// See [`crate::NativeOptions::control_flow_override`].
+    fn control_flow_override(&self) -> Option<winit::event_loop::ControlFlow>;
+
+    /// See [`crate::Frame::set_exit_code`]. Zero before the app has started running.
+    fn exit_code(&self) -> i32;
+
     fn save(&mut self);
 
     fn save_and_destroy(&mut self);
@@ -102,6 +214,12 @@ pub trait WinitApp {
 
     #[cfg(feature = "accesskit")]
     fn on_accesskit_event(&mut self, event: accesskit_winit::Event) -> crate::Result<EventResult>;
+
+    #[cfg(feature = "tray_icon")]
+    fn on_tray_event(&mut self, id: u32) -> crate::Result<EventResult>;
+
+    fn on_user_event(&mut self, event: Box<dyn std::any::Any + Send>)
+    -> crate::Result<EventResult>;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
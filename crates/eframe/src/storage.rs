@@ -0,0 +1,61 @@
+//! [`Storage`] backends that are not tied to a platform.
+
+use std::collections::HashMap;
+
+use crate::Storage;
+
+/// A [`Storage`] that lives entirely in memory.
+///
+/// Useful for testing [`crate::App::save`] and restoring state without touching the file system:
+///
+/// ```
+/// use eframe::{Storage as _, storage::MemoryStorage};
+///
+/// let storage = MemoryStorage::from_iter([("app".to_owned(), "42".to_owned())]);
+/// assert_eq!(storage.get_string("app").as_deref(), Some("42"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    kv: HashMap<String, String>,
+}
+
+impl MemoryStorage {
+    /// An empty storage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FromIterator<(String, String)> for MemoryStorage {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self {
+            kv: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.kv.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.kv.insert(key.to_owned(), value);
+    }
+
+    fn flush(&mut self) {}
+
+    fn list_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.kv.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    fn delete_key(&mut self, key: &str) {
+        self.kv.remove(key);
+    }
+
+    fn clear(&mut self) {
+        self.kv.clear();
+    }
+}
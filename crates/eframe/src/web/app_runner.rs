@@ -14,6 +14,16 @@ pub struct AppRunner {
     app: Box<dyn epi::App>,
     pub(crate) needs_repaint: std::sync::Arc<NeedRepaint>,
     last_save_time: f64,
+
+    /// When the runner was created, in [`now_sec`] scale.
+    start_time: f64,
+
+    /// Rolling window of recent frame times, for [`epi::FrameStats::fps_smoothed`].
+    frame_time_history: egui::util::History<f32>,
+
+    /// When we last ran [`Self::logic`] from the animation frame loop, in [`now_sec`] scale.
+    pub(crate) last_frame_time: f64,
+
     pub(crate) text_agent: TextAgent,
 
     // If not empty, the painter should capture n frames from now.
@@ -49,13 +59,17 @@ impl AppRunner {
                 location: super::web_location(),
             },
             cpu_usage: None,
+            cpu_usage_history: Default::default(),
+            update_time: None,
+            tessellation_time: None,
+            paint_time: None,
         };
-        let storage = LocalStorage::default();
+        let mut storage = LocalStorage::default();
 
         egui_ctx.set_os(egui::os::OperatingSystem::from_user_agent(
             &super::user_agent().unwrap_or_default(),
         ));
-        super::storage::load_memory(&egui_ctx);
+        let memory_load_error = super::storage::load_memory(&egui_ctx);
 
         egui_ctx.options_mut(|o| {
             // On web by default egui follows the zoom factor of the browser,
@@ -70,6 +84,11 @@ impl AppRunner {
             integration_info: info.clone(),
             storage: Some(&storage),
 
+            #[cfg(feature = "glow")]
+            renderer: epi::Renderer::Glow,
+            #[cfg(all(feature = "wgpu", not(feature = "glow")))]
+            renderer: epi::Renderer::Wgpu,
+
             #[cfg(feature = "glow")]
             gl: Some(painter.gl().clone()),
 
@@ -81,11 +100,21 @@ impl AppRunner {
             #[cfg(all(feature = "wgpu", feature = "glow"))]
             wgpu_render_state: None,
         };
-        let app = app_creator(&cc).map_err(|err| err.to_string())?;
+        let mut app = app_creator(&cc).map_err(|err| err.to_string())?;
+
+        if let Some(err) = memory_load_error {
+            app.on_storage_load_error(super::storage::EGUI_MEMORY_KEY, err.as_ref());
+        }
+        epi::migrate_storage(app.as_mut(), &mut storage);
 
         let frame = epi::Frame {
             info,
+            frame_stats: Default::default(),
+            monitor: None,
+            screen_size_in_pixels: None,
             storage: Some(Box::new(storage)),
+            auto_save_interval: None,
+            title: None,
 
             #[cfg(feature = "glow")]
             gl: Some(painter.gl().clone()),
@@ -113,6 +142,9 @@ impl AppRunner {
             app,
             needs_repaint,
             last_save_time: now_sec(),
+            start_time: now_sec(),
+            frame_time_history: epi::FrameStats::new_history(),
+            last_frame_time: f64::NEG_INFINITY,
             text_agent,
             screenshot_commands_with_frame_delay: vec![],
             textures_delta: Default::default(),
@@ -127,7 +159,9 @@ impl AppRunner {
             .entry(egui::ViewportId::ROOT)
             .or_default()
             .native_pixels_per_point = Some(super::native_pixels_per_point());
-        runner.input.raw.system_theme = super::system_theme();
+        if runner.web_options.follow_system_theme {
+            runner.input.raw.system_theme = super::system_theme();
+        }
 
         Ok(runner)
     }
@@ -147,16 +181,43 @@ impl AppRunner {
             .expect("app_mut got the wrong type of App")
     }
 
+    /// Get read-only access to the concrete [`App`] we enclose.
+    ///
+    /// This will panic if your app does not implement [`App::as_any`].
+    pub fn app_ref<ConcreteApp: 'static + App>(&self) -> &ConcreteApp {
+        self.app
+            .as_any()
+            .expect("Your app must implement `as_any`, but it doesn't")
+            .downcast_ref::<ConcreteApp>()
+            .expect("app_ref got the wrong type of App")
+    }
+
+    /// Is it too soon for another frame, according to [`crate::WebOptions::max_fps`]?
+    pub(crate) fn is_frame_rate_limited(&self) -> bool {
+        self.web_options
+            .max_fps
+            .filter(|max_fps| 0.0 < *max_fps)
+            .is_some_and(|max_fps| now_sec() - self.last_frame_time < 1.0 / max_fps as f64)
+    }
+
     pub fn auto_save_if_needed(&mut self) {
         let time_since_last_save = now_sec() - self.last_save_time;
-        if time_since_last_save > self.app.auto_save_interval().as_secs_f64() {
+        let auto_save_interval = self
+            .frame
+            .auto_save_interval
+            .unwrap_or_else(|| self.app.auto_save_interval());
+        if epi::is_auto_save_due(
+            std::time::Duration::from_secs_f64(time_since_last_save.max(0.0)),
+            auto_save_interval,
+        ) {
             self.save();
         }
     }
 
     pub fn save(&mut self) {
-        if self.app.persist_egui_memory() {
-            super::storage::save_memory(&self.egui_ctx);
+        let memory_flags = self.app.persist_egui_memory_flags();
+        if !memory_flags.is_empty() {
+            super::storage::save_memory(&self.egui_ctx, memory_flags);
         }
         if let Some(storage) = self.frame.storage_mut() {
             self.app.save(storage);
@@ -194,6 +255,7 @@ impl AppRunner {
         let has_focus = self.has_focus();
         if self.input.raw.focused != has_focus {
             log::trace!("{} Focus changed to {has_focus}", self.canvas().id());
+            self.app.on_focus_changed(&self.egui_ctx, has_focus);
             self.input.set_focus(has_focus);
 
             if !has_focus {
@@ -228,11 +290,35 @@ impl AppRunner {
             );
         }
 
+        self.frame.monitor = super::current_monitor();
+        self.frame.screen_size_in_pixels = Some([self.canvas().width(), self.canvas().height()]);
+
+        self.app.pre_frame(&self.egui_ctx, &mut self.frame);
         self.app.raw_input_hook(&self.egui_ctx, &mut raw_input);
 
-        let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
+        let mut dropped_paths: Vec<std::path::PathBuf> = raw_input
+            .dropped_files
+            .iter()
+            .map(|file| std::path::PathBuf::from(&file.name))
+            .collect();
+
+        let mut full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
+            let update_start = now_sec();
             self.app.update(egui_ctx, &mut self.frame);
+            self.frame.info.update_time = Some((now_sec() - update_start) as f32);
+
+            if let Some(title) = self.frame.title.take() {
+                egui_ctx.send_viewport_cmd(ViewportCommand::Title(title));
+            }
+
+            if !dropped_paths.is_empty() {
+                let paths = std::mem::take(&mut dropped_paths);
+                self.app.on_drop_files(egui_ctx, &paths);
+            }
         });
+
+        self.app.raw_output_hook(&self.egui_ctx, &mut full_output);
+
         let egui::FullOutput {
             platform_output,
             textures_delta,
@@ -251,6 +337,11 @@ impl AppRunner {
                         self.screenshot_commands_with_frame_delay
                             .push((user_data, 1));
                     }
+                    ViewportCommand::Title(title) => {
+                        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+                            document.set_title(&title);
+                        }
+                    }
                     _ => {
                         // TODO(emilk): handle some of the commands
                         log::warn!(
@@ -263,7 +354,9 @@ impl AppRunner {
 
         self.handle_platform_output(platform_output);
         self.textures_delta.append(textures_delta);
+        let tessellation_start = now_sec();
         self.clipped_primitives = Some(self.egui_ctx.tessellate(shapes, pixels_per_point));
+        self.frame.info.tessellation_time = Some((now_sec() - tessellation_start) as f32);
     }
 
     /// Paint the results of the last call to [`Self::logic`].
@@ -287,6 +380,7 @@ impl AppRunner {
                 self.egui_ctx().request_repaint();
             }
 
+            let paint_start = now_sec();
             if let Err(err) = self.painter.paint_and_update_textures(
                 self.app.clear_color(&self.egui_ctx.style().visuals),
                 &clipped_primitives,
@@ -296,11 +390,20 @@ impl AppRunner {
             ) {
                 log::error!("Failed to paint: {}", super::string_from_js_value(&err));
             }
+            self.frame.info.paint_time = Some((now_sec() - paint_start) as f32);
+
+            let window_size_px = [self.canvas().width(), self.canvas().height()];
+            self.app.post_rendering(window_size_px, &mut self.frame);
         }
     }
 
     pub fn report_frame_time(&mut self, cpu_usage_seconds: f32) {
-        self.frame.info.cpu_usage = Some(cpu_usage_seconds);
+        self.frame.info.report_cpu_usage(cpu_usage_seconds);
+        self.frame.frame_stats.report(
+            &mut self.frame_time_history,
+            now_sec() - self.start_time,
+            cpu_usage_seconds,
+        );
     }
 
     fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
@@ -79,6 +79,11 @@ impl NeedRepaint {
         self.when_to_repaint() <= super::now_sec()
     }
 
+    /// Is there any repaint scheduled, now or in the future?
+    pub fn is_scheduled(&self) -> bool {
+        self.when_to_repaint() < f64::INFINITY
+    }
+
     pub fn repaint_asap(&self) {
         *self.0.lock() = f64::NEG_INFINITY;
     }
@@ -37,7 +37,11 @@ fn paint_if_needed(runner: &mut AppRunner) {
             // We schedule another repaint asap, so that we can run the actual logic
             // again, which may schedule a new repaint (if there's animations):
             runner.needs_repaint.repaint_asap();
+        } else if runner.is_frame_rate_limited() {
+            // Too soon - we will get another chance on the next animation frame.
         } else {
+            runner.last_frame_time = super::now_sec();
+
             // Clear the `needs_repaint` flags _before_
             // running the logic, as the logic could cause it to be set again.
             runner.needs_repaint.clear();
@@ -103,7 +107,14 @@ pub(crate) fn install_event_handlers(runner_ref: &WebRunner) -> Result<(), JsVal
     install_wheel(runner_ref, &canvas)?;
     install_drag_and_drop(runner_ref, &canvas)?;
     install_window_events(runner_ref, &window)?;
-    install_color_scheme_change_event(runner_ref, &window)?;
+    if runner_ref
+        .try_lock()
+        .unwrap()
+        .web_options
+        .follow_system_theme
+    {
+        install_color_scheme_change_event(runner_ref, &window)?;
+    }
     Ok(())
 }
 
@@ -138,10 +149,11 @@ fn install_keydown(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), J
             {
                 if let Some(text) = text_from_keyboard_event(&event) {
                     let egui_event = egui::Event::Text(text);
-                    let should_stop_propagation =
-                        (runner.web_options.should_stop_propagation)(&egui_event);
-                    let should_prevent_default =
-                        (runner.web_options.should_prevent_default)(&egui_event);
+                    let should_propagate = should_propagate_event(runner, &egui_event);
+                    let should_stop_propagation = !should_propagate
+                        && (runner.web_options.should_stop_propagation)(&egui_event);
+                    let should_prevent_default = !should_propagate
+                        && (runner.web_options.should_prevent_default)(&egui_event);
                     runner.input.raw.events.push(egui_event);
                     runner.needs_repaint.repaint_asap();
 
@@ -188,11 +200,14 @@ pub(crate) fn on_keydown(event: web_sys::KeyboardEvent, runner: &mut AppRunner)
             repeat: false, // egui will fill this in for us!
             modifiers,
         };
-        let should_stop_propagation = (runner.web_options.should_stop_propagation)(&egui_event);
+        let should_propagate = should_propagate_event(runner, &egui_event);
+        let should_stop_propagation =
+            !should_propagate && (runner.web_options.should_stop_propagation)(&egui_event);
         runner.input.raw.events.push(egui_event);
         runner.needs_repaint.repaint_asap();
 
-        let prevent_default = should_prevent_default_for_key(runner, &modifiers, egui_key);
+        let prevent_default =
+            !should_propagate && should_prevent_default_for_key(runner, &modifiers, egui_key);
 
         // log::debug!(
         //     "On keydown {:?} {egui_key:?}, has_focus: {has_focus}, egui_wants_keyboard: {}, prevent_default: {prevent_default}",
@@ -211,6 +226,17 @@ pub(crate) fn on_keydown(event: web_sys::KeyboardEvent, runner: &mut AppRunner)
     }
 }
 
+/// Has the user asked for this keyboard event to be left to the rest of the web page?
+///
+/// See [`crate::WebOptions::should_propagate_event`].
+fn should_propagate_event(runner: &AppRunner, egui_event: &egui::Event) -> bool {
+    runner
+        .web_options
+        .should_propagate_event
+        .as_ref()
+        .is_some_and(|should_propagate_event| should_propagate_event(egui_event))
+}
+
 /// If the canvas (or text agent) has focus:
 /// should we prevent the default browser event action when the user presses this key?
 fn should_prevent_default_for_key(
@@ -275,7 +301,8 @@ pub(crate) fn on_keyup(event: web_sys::KeyboardEvent, runner: &mut AppRunner) {
             repeat: false,
             modifiers,
         };
-        should_stop_propagation &= (runner.web_options.should_stop_propagation)(&egui_event);
+        should_stop_propagation &= !should_propagate_event(runner, &egui_event)
+            && (runner.web_options.should_stop_propagation)(&egui_event);
         runner.input.raw.events.push(egui_event);
     }
 
@@ -943,7 +970,8 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
     Ok(())
 }
 
-/// A `ResizeObserver` is used to observe changes to the size of the canvas.
+/// A `ResizeObserver` is used to observe changes to the size of the canvas,
+/// or of its parent element with [`crate::CanvasResizePolicy::Container`].
 ///
 /// The resize observer is called the by the browser at `observe` time, instead of just on the first actual resize.
 /// We use that to trigger the first `request_animation_frame` _after_ updating the size of the canvas to the correct dimensions,
@@ -962,7 +990,9 @@ impl Drop for ResizeObserverContext {
 }
 
 impl ResizeObserverContext {
-    pub fn new(runner_ref: &WebRunner) -> Result<Self, JsValue> {
+    /// If `follow_container` is set, the observed element is the parent of the canvas,
+    /// and the canvas is given the same CSS size.
+    pub fn new(runner_ref: &WebRunner, follow_container: bool) -> Result<Self, JsValue> {
         let closure = Closure::wrap(Box::new({
             let runner_ref = runner_ref.clone();
             move |entries: js_sys::Array| {
@@ -988,6 +1018,15 @@ impl ResizeObserverContext {
                     canvas.set_width(width);
                     canvas.set_height(height);
 
+                    if follow_container {
+                        let dpr = native_pixels_per_point();
+                        if let Err(err) =
+                            set_canvas_css_size(canvas, width as f32 / dpr, height as f32 / dpr)
+                        {
+                            log::error!("{}", super::string_from_js_value(&err));
+                        }
+                    }
+
                     // force an immediate repaint
                     runner_lock.needs_repaint.repaint_asap();
                     paint_if_needed(&mut runner_lock);
@@ -1010,14 +1049,71 @@ impl ResizeObserverContext {
         })
     }
 
-    pub fn observe(&self, canvas: &web_sys::HtmlCanvasElement) {
+    /// Observe the canvas, or its parent element.
+    pub fn observe(&self, element: &web_sys::Element) {
         if DEBUG_RESIZE {
-            log::info!("Calling observe on canvas…");
+            log::info!("Calling observe on {}…", element.tag_name());
         }
         let options = web_sys::ResizeObserverOptions::new();
         options.set_box(web_sys::ResizeObserverBoxOptions::ContentBox);
-        self.observer.observe_with_options(canvas, &options);
+        self.observer.observe_with_options(element, &options);
+    }
+}
+
+/// Set the size of the canvas as laid out on the page, in CSS pixels.
+pub(crate) fn set_canvas_css_size(
+    canvas: &web_sys::HtmlCanvasElement,
+    width: f32,
+    height: f32,
+) -> Result<(), JsValue> {
+    let style = canvas.style();
+    style.set_property("width", &format!("{width}px"))?;
+    style.set_property("height", &format!("{height}px"))?;
+    Ok(())
+}
+
+/// Size the canvas from the current page layout and repaint immediately,
+/// without waiting for the `ResizeObserver`.
+///
+/// See [`WebRunner::resize_canvas`].
+pub(crate) fn resize_canvas(runner: &mut AppRunner) -> Result<(), JsValue> {
+    let dpr = native_pixels_per_point();
+    let canvas = runner.canvas();
+
+    match runner.web_options.canvas_resize_policy {
+        crate::CanvasResizePolicy::Window => {
+            let rect = canvas.get_bounding_client_rect();
+            canvas.set_width((rect.width() as f32 * dpr).round() as u32);
+            canvas.set_height((rect.height() as f32 * dpr).round() as u32);
+        }
+        crate::CanvasResizePolicy::Container => {
+            let container = canvas
+                .parent_element()
+                .ok_or_else(|| JsValue::from_str("The canvas has no parent element"))?;
+            let (width, height) = (
+                container.client_width() as f32,
+                container.client_height() as f32,
+            );
+            set_canvas_css_size(canvas, width, height)?;
+            canvas.set_width((width * dpr).round() as u32);
+            canvas.set_height((height * dpr).round() as u32);
+        }
+        crate::CanvasResizePolicy::Fixed { .. } => {
+            // The size never changes, but the caller still expects a fresh frame.
+        }
+    }
+
+    if DEBUG_RESIZE {
+        log::info!(
+            "resize_canvas: new canvas size: {}x{}, DPR: {dpr}",
+            canvas.width(),
+            canvas.height()
+        );
     }
+
+    runner.needs_repaint.repaint_asap();
+    paint_if_needed(runner);
+    Ok(())
 }
 
 // Code ported to Rust from:
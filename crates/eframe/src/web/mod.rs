@@ -110,6 +110,24 @@ pub fn native_pixels_per_point() -> f32 {
     }
 }
 
+/// The screen the browser window is on, from [`window.screen`](https://developer.mozilla.org/en-US/docs/Web/API/Window/screen)
+/// and [`window.devicePixelRatio`](https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio).
+///
+/// Browsers don't expose the name or refresh rate of the screen.
+pub fn current_monitor() -> Option<crate::MonitorInfo> {
+    let window = web_sys::window()?;
+    let screen = window.screen().ok()?;
+    let scale_factor = window.device_pixel_ratio();
+    let width_px = screen.width().ok()? as f64 * scale_factor;
+    let height_px = screen.height().ok()? as f64 * scale_factor;
+    Some(crate::MonitorInfo {
+        size_px: [width_px.round() as u32, height_px.round() as u32],
+        scale_factor,
+        name: None,
+        refresh_rate_hz: None,
+    })
+}
+
 /// Ask the browser about the preferred system theme.
 ///
 /// `None` means unknown.
@@ -12,28 +12,44 @@ pub fn local_storage_set(key: &str, value: &str) {
     local_storage().map(|storage| storage.set_item(key, value));
 }
 
+/// Local storage key of the egui memory.
+pub(crate) const EGUI_MEMORY_KEY: &str = "egui_memory_ron";
+
+/// Returns the error if the stored memory could not be parsed.
 #[cfg(feature = "persistence")]
-pub(crate) fn load_memory(ctx: &egui::Context) {
-    if let Some(memory_string) = local_storage_get("egui_memory_ron") {
-        match ron::from_str(&memory_string) {
-            Ok(memory) => {
-                ctx.memory_mut(|m| *m = memory);
-            }
-            Err(err) => {
-                log::warn!("Failed to parse memory RON: {err}");
-            }
+pub(crate) fn load_memory(ctx: &egui::Context) -> Option<Box<dyn std::error::Error>> {
+    let memory_string = local_storage_get(EGUI_MEMORY_KEY)?;
+    match ron::from_str(&memory_string) {
+        Ok(memory) => {
+            ctx.memory_mut(|m| *m = memory);
+            None
+        }
+        Err(err) => {
+            log::warn!("Failed to parse memory RON: {err}");
+            Some(Box::new(err))
         }
     }
 }
 
 #[cfg(not(feature = "persistence"))]
-pub(crate) fn load_memory(_: &egui::Context) {}
+pub(crate) fn load_memory(_: &egui::Context) -> Option<Box<dyn std::error::Error>> {
+    None
+}
 
 #[cfg(feature = "persistence")]
-pub(crate) fn save_memory(ctx: &egui::Context) {
-    match ctx.memory(ron::to_string) {
+pub(crate) fn save_memory(ctx: &egui::Context, flags: crate::MemoryPersistFlags) {
+    let ron = ctx.memory(|mem| {
+        if flags.is_all() {
+            ron::to_string(mem)
+        } else {
+            let mut mem = mem.clone();
+            flags.filter_memory(&mut mem);
+            ron::to_string(&mem)
+        }
+    });
+    match ron {
         Ok(ron) => {
-            local_storage_set("egui_memory_ron", &ron);
+            local_storage_set(EGUI_MEMORY_KEY, &ron);
         }
         Err(err) => {
             log::warn!("Failed to serialize memory as RON: {err}");
@@ -42,4 +58,4 @@ pub(crate) fn save_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub(crate) fn save_memory(_: &egui::Context) {}
+pub(crate) fn save_memory(_: &egui::Context, _: crate::MemoryPersistFlags) {}
@@ -1,6 +1,18 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+/// The most recent log records, see [`WebLogger::recent`].
+static RECENT_RECORDS: Mutex<VecDeque<(log::Level, String)>> = Mutex::new(VecDeque::new());
+
 /// Implements [`log::Log`] to log messages to `console.log`, `console.warn`, etc.
 pub struct WebLogger {
     filter: log::LevelFilter,
+
+    /// How many records to keep for [`Self::recent`].
+    recent_capacity: usize,
+
+    /// Log each record as a JSON object, see [`Self::init_json`].
+    #[cfg(feature = "web-json-logging")]
+    json: bool,
 }
 
 impl WebLogger {
@@ -10,10 +22,79 @@ impl WebLogger {
         log::set_boxed_logger(Box::new(Self::new(filter)))
     }
 
+    /// Like [`Self::init`], but also keep the last `capacity` log records in memory,
+    /// so you can show them in your app with [`Self::recent`].
+    pub fn init_with_recent(
+        filter: log::LevelFilter,
+        capacity: usize,
+    ) -> Result<(), log::SetLoggerError> {
+        log::set_max_level(filter);
+        log::set_boxed_logger(Box::new(Self::new(filter).with_recent(capacity)))
+    }
+
+    /// Install a new `WebLogger` that logs each [`log`] event to the web console
+    /// as a JSON object, for easy consumption by log aggregators.
+    ///
+    /// The object has the fields `level`, `target`, `message` and `timestamp_ms`
+    /// (milliseconds since the Unix epoch), plus any key-value pairs attached to the record.
+    ///
+    /// Requires the `web-json-logging` feature.
+    #[cfg(feature = "web-json-logging")]
+    pub fn init_json(filter: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+        log::set_max_level(filter);
+        log::set_boxed_logger(Box::new(Self {
+            json: true,
+            ..Self::new(filter)
+        }))
+    }
+
     /// Create a new [`WebLogger`] with the given filter,
     /// but don't install it.
     pub fn new(filter: log::LevelFilter) -> Self {
-        Self { filter }
+        Self {
+            filter,
+            recent_capacity: 0,
+            #[cfg(feature = "web-json-logging")]
+            json: false,
+        }
+    }
+
+    /// Keep the last `capacity` log records in memory, for [`Self::recent`].
+    ///
+    /// The records are still logged to the web console too.
+    #[inline]
+    pub fn with_recent(mut self, capacity: usize) -> Self {
+        self.recent_capacity = capacity;
+        self
+    }
+
+    /// The most recent log records, oldest first.
+    ///
+    /// Only records that passed the filter are kept,
+    /// and only if the installed logger was created with [`Self::with_recent`]
+    /// or [`Self::init_with_recent`].
+    ///
+    /// Useful for showing a log console inside your app.
+    pub fn recent() -> Vec<(log::Level, String)> {
+        RECENT_RECORDS
+            .lock()
+            .map(|records| records.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn remember(&self, record: &log::Record<'_>) {
+        if self.recent_capacity == 0 {
+            return;
+        }
+        if let Ok(mut records) = RECENT_RECORDS.lock() {
+            while self.recent_capacity <= records.len() {
+                records.pop_front();
+            }
+            records.push_back((
+                record.level(),
+                format!("[{}] {}", record.target(), record.args()),
+            ));
+        }
     }
 }
 
@@ -44,6 +125,19 @@ impl log::Log for WebLogger {
             return;
         }
 
+        self.remember(record);
+
+        #[cfg(feature = "web-json-logging")]
+        if self.json {
+            let msg = json_record(record);
+            match record.level() {
+                log::Level::Trace | log::Level::Debug => console::debug(&msg),
+                log::Level::Info => console::info(&msg),
+                log::Level::Warn | log::Level::Error => console::warn(&msg),
+            }
+            return;
+        }
+
         let msg = if let (Some(file), Some(line)) = (record.file(), record.line()) {
             let file = shorten_file_path(file);
             format!("[{}] {file}:{line}: {}", record.target(), record.args())
@@ -69,6 +163,35 @@ impl log::Log for WebLogger {
     fn flush(&self) {}
 }
 
+/// Format a record as a single-line JSON object, see [`WebLogger::init_json`].
+#[cfg(feature = "web-json-logging")]
+fn json_record(record: &log::Record<'_>) -> String {
+    struct KeyValues(serde_json::Map<String, serde_json::Value>);
+
+    impl<'kvs> log::kv::VisitSource<'kvs> for KeyValues {
+        fn visit_pair(
+            &mut self,
+            key: log::kv::Key<'kvs>,
+            value: log::kv::Value<'kvs>,
+        ) -> Result<(), log::kv::Error> {
+            self.0.insert(key.to_string(), value.to_string().into());
+            Ok(())
+        }
+    }
+
+    let mut fields = KeyValues(serde_json::Map::new());
+    record.key_values().visit(&mut fields).ok();
+
+    // The standard fields win over key-value pairs with the same name:
+    let mut fields = fields.0;
+    fields.insert("level".to_owned(), record.level().as_str().into());
+    fields.insert("target".to_owned(), record.target().into());
+    fields.insert("message".to_owned(), record.args().to_string().into());
+    fields.insert("timestamp_ms".to_owned(), js_sys::Date::now().into());
+
+    serde_json::Value::Object(fields).to_string()
+}
+
 /// js-bindings for console.log, console.warn, etc
 mod console {
     use wasm_bindgen::prelude::*;
@@ -1,8 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 use wasm_bindgen::prelude::*;
 
-use crate::{App, epi};
+use crate::{App, CanvasResizePolicy, epi};
 
 use super::{
     AppRunner, PanicHandler,
@@ -33,6 +36,9 @@ pub struct WebRunner {
     frame: Rc<RefCell<Option<AnimationFrameRequest>>>,
 
     resize_observer: Rc<RefCell<Option<ResizeObserverContext>>>,
+
+    /// Set by [`Self::pause`]: no new animation frames are requested while this is set.
+    paused: Rc<Cell<bool>>,
 }
 
 impl WebRunner {
@@ -47,6 +53,7 @@ impl WebRunner {
             events_to_unsubscribe: Rc::new(RefCell::new(Default::default())),
             frame: Default::default(),
             resize_observer: Default::default(),
+            paused: Default::default(),
         }
     }
 
@@ -71,6 +78,8 @@ impl WebRunner {
             canvas.style().set_property("outline", "none")?;
         }
 
+        let canvas_resize_policy = web_options.canvas_resize_policy;
+
         {
             // First set up the app runner:
             let text_agent = TextAgent::attach(self, canvas.get_root_node())?;
@@ -79,13 +88,40 @@ impl WebRunner {
             self.app_runner.replace(Some(app_runner));
         }
 
-        {
-            let resize_observer = events::ResizeObserverContext::new(self)?;
+        match canvas_resize_policy {
+            CanvasResizePolicy::Window => {
+                let resize_observer = events::ResizeObserverContext::new(self, false)?;
+
+                // Properly size the canvas. Will also call `self.request_animation_frame()` (eventually)
+                resize_observer.observe(&canvas);
+
+                self.resize_observer.replace(Some(resize_observer));
+            }
+
+            CanvasResizePolicy::Container => {
+                let container = canvas
+                    .parent_element()
+                    .ok_or_else(|| JsValue::from_str("The canvas has no parent element"))?;
 
-            // Properly size the canvas. Will also call `self.request_animation_frame()` (eventually)
-            resize_observer.observe(&canvas);
+                // Avoid the extra space below inline elements, which would make the container grow:
+                canvas.style().set_property("display", "block")?;
 
-            self.resize_observer.replace(Some(resize_observer));
+                let resize_observer = events::ResizeObserverContext::new(self, true)?;
+
+                // Properly size the canvas. Will also call `self.request_animation_frame()` (eventually)
+                resize_observer.observe(&container);
+
+                self.resize_observer.replace(Some(resize_observer));
+            }
+
+            CanvasResizePolicy::Fixed { width, height } => {
+                let dpr = super::native_pixels_per_point();
+                events::set_canvas_css_size(&canvas, width as f32, height as f32)?;
+                canvas.set_width((width as f32 * dpr).round() as u32);
+                canvas.set_height((height as f32 * dpr).round() as u32);
+
+                self.request_animation_frame()?;
+            }
         }
 
         events::install_event_handlers(self)?;
@@ -95,6 +131,25 @@ impl WebRunner {
         Ok(())
     }
 
+    /// Like [`Self::start`], but the app is created by a future.
+    ///
+    /// The future is awaited before the app starts running.
+    ///
+    /// # Errors
+    /// Failing to initialize graphics, or failure to create app.
+    pub async fn start_async(
+        &self,
+        canvas: web_sys::HtmlCanvasElement,
+        web_options: crate::WebOptions,
+        app_future: epi::AppFuture,
+    ) -> Result<(), JsValue> {
+        let app = app_future
+            .await
+            .map_err(|err| JsValue::from(err.to_string()))?;
+        self.start(canvas, web_options, Box::new(move |_cc| Ok(app)))
+            .await
+    }
+
     /// Has there been a panic?
     pub fn has_panicked(&self) -> bool {
         self.panic_handler.has_panicked()
@@ -138,6 +193,51 @@ impl WebRunner {
         }
     }
 
+    /// Stop running the app, e.g. when the user navigates away or the tab is hidden.
+    ///
+    /// The frame currently in flight (if any) still runs, but no new frames are scheduled
+    /// until [`Self::resume`] is called.
+    /// [`Self::has_panicked`] and [`Self::destroy`] keep working while paused.
+    pub fn pause(&self) {
+        self.paused.set(true);
+    }
+
+    /// Start running the app again after [`Self::pause`].
+    pub fn resume(&self) {
+        if self.paused.replace(false) {
+            if let Some(mut runner_lock) = self.try_lock() {
+                // Catch up on anything that happened while we were paused:
+                runner_lock.needs_repaint.repaint_asap();
+            }
+            if let Err(err) = self.request_animation_frame() {
+                log::error!("{}", super::string_from_js_value(&err));
+            }
+        }
+    }
+
+    /// Has [`Self::pause`] been called, without a matching [`Self::resume`]?
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// How many frames have been painted so far.
+    ///
+    /// Returns zero if the runner has panicked or been destroyed.
+    pub fn frame_nr(&self) -> u64 {
+        self.try_lock()
+            .map_or(0, |runner| runner.egui_ctx().cumulative_frame_nr())
+    }
+
+    /// Is the app currently idle, i.e. is there no repaint scheduled?
+    ///
+    /// This is also `true` while [paused](Self::pause), or if the runner has panicked or been destroyed.
+    pub fn is_idle(&self) -> bool {
+        self.is_paused()
+            || self
+                .try_lock()
+                .is_none_or(|runner| !runner.needs_repaint.is_scheduled())
+    }
+
     /// Returns `None` if there has been a panic, or if we have been destroyed.
     /// In that case, just return to JS.
     pub(crate) fn try_lock(&self) -> Option<std::cell::RefMut<'_, AppRunner>> {
@@ -164,6 +264,43 @@ impl WebRunner {
             .map(|lock| std::cell::RefMut::map(lock, |runner| runner.app_mut::<ConcreteApp>()))
     }
 
+    /// Get read-only access to the concrete [`App`] we enclose,
+    /// e.g. to serialize its state from a JavaScript callback.
+    ///
+    /// Several of these can be held at once, but none while the app is running a frame.
+    ///
+    /// This will panic if your app does not implement [`App::as_any`],
+    /// and return `None` if this runner has panicked, or is currently borrowed mutably.
+    pub fn app_ref<ConcreteApp: 'static + App>(&self) -> Option<std::cell::Ref<'_, ConcreteApp>> {
+        if self.panic_handler.has_panicked() {
+            return None;
+        }
+        let lock = self.app_runner.try_borrow().ok()?;
+        let runner = std::cell::Ref::filter_map(lock, |lock| lock.as_ref()).ok()?;
+        Some(std::cell::Ref::map(runner, |runner| {
+            runner.app_ref::<ConcreteApp>()
+        }))
+    }
+
+    /// Re-read the size of the canvas from the page layout and repaint right away.
+    ///
+    /// The canvas is normally resized automatically (see [`crate::CanvasResizePolicy`]),
+    /// but the browser only reports the new size a frame later, which shows up as a flicker.
+    /// Call this (e.g. from your own `#[wasm_bindgen]` glue) right after changing the layout from JavaScript.
+    ///
+    /// Does nothing if the runner has panicked or been destroyed.
+    ///
+    /// # Errors
+    /// If the canvas has no parent element with [`crate::CanvasResizePolicy::Container`].
+    pub fn resize_canvas(&self) -> Result<(), JsValue> {
+        if let Some(mut runner_lock) = self.try_lock() {
+            events::resize_canvas(&mut runner_lock)?;
+            drop(runner_lock);
+            self.request_animation_frame()?;
+        }
+        Ok(())
+    }
+
     /// Convenience function to reduce boilerplate and ensure that all event handlers
     /// are dealt with in the same way.
     ///
@@ -240,6 +377,11 @@ impl WebRunner {
             return Ok(());
         }
 
+        if self.paused.get() {
+            // `resume` will request a new frame
+            return Ok(());
+        }
+
         let window = web_sys::window().unwrap();
         let closure = Closure::once({
             let web_runner = self.clone();
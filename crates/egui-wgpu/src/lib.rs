@@ -57,6 +57,12 @@ pub enum WgpuError {
     #[error(transparent)]
     CreateSurfaceError(#[from] wgpu::CreateSurfaceError),
 
+    #[error(transparent)]
+    PollError(#[from] wgpu::PollError),
+
+    #[error(transparent)]
+    BufferAsyncError(#[from] wgpu::BufferAsyncError),
+
     #[cfg(feature = "winit")]
     #[error(transparent)]
     HandleError(#[from] ::winit::raw_window_handle::HandleError),
@@ -1704,7 +1704,7 @@ pub fn create_winit_window_attributes(
             }
             None => WindowAttributesWayland::default(),
         };
-        match (_app_id, _app_instance) {
+        match (&_app_id, &_app_instance) {
             (Some(app_id), Some(app_instance)) => {
                 wayland_attributes = wayland_attributes.with_name(app_id, app_instance);
             }
@@ -1723,6 +1723,16 @@ pub fn create_winit_window_attributes(
     #[cfg(all(feature = "x11", target_os = "linux"))]
     {
         let mut x11_attributes = winit::platform::x11::WindowAttributesX11::default();
+        // `WM_CLASS` is the X11 equivalent of the Wayland application ID:
+        match (&_app_id, &_app_instance) {
+            (Some(app_id), Some(app_instance)) => {
+                x11_attributes = x11_attributes.with_name(app_id, app_instance);
+            }
+            (Some(app_id), None) => {
+                x11_attributes = x11_attributes.with_name(app_id, app_id);
+            }
+            _ => {}
+        }
         if let Some(window_type) = _window_type {
             use winit::platform::x11::WindowType;
             x11_attributes = x11_attributes.with_x11_window_type(vec![match window_type {
@@ -1,7 +1,7 @@
 use egui::ViewportBuilder;
 
 /// Can be used to store native window settings (position and size).
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct WindowSettings {
@@ -49,6 +49,16 @@ impl WindowSettings {
         }
     }
 
+    /// The name of the monitor the window is on, if known.
+    ///
+    /// Store it next to the settings, for [`Self::clamp_to_named_monitor`].
+    pub fn monitor_name(window: &dyn winit::window::Window) -> Option<String> {
+        window
+            .current_monitor()
+            .and_then(|monitor| monitor.name())
+            .map(|name| name.to_string())
+    }
+
     pub fn inner_size_points(&self) -> Option<egui::Vec2> {
         self.inner_size_points
     }
@@ -115,6 +125,44 @@ impl WindowSettings {
         }
     }
 
+    /// If the monitor called `monitor_name` is still connected,
+    /// move the window fully onto it, even if the monitors have been rearranged.
+    ///
+    /// `monitor_name` is the name of the monitor the window was last on,
+    /// e.g. from [`Self::monitor_name`], stored next to these settings.
+    ///
+    /// Returns `false` if there is no such monitor (e.g. it was unplugged),
+    /// in which case you probably want to call [`Self::clamp_position_to_monitors`] instead.
+    pub fn clamp_to_named_monitor(
+        &mut self,
+        egui_zoom_factor: f32,
+        event_loop: &dyn winit::event_loop::ActiveEventLoop,
+        monitor_name: &str,
+    ) -> bool {
+        profiling::function_scope!();
+
+        let Some(monitor) = event_loop
+            .available_monitors()
+            .find(|monitor| monitor.name().is_some_and(|name| name == monitor_name))
+        else {
+            log::debug!("Monitor {monitor_name:?} is no longer connected");
+            return false;
+        };
+
+        let Some(inner_size_points) = self.inner_size_points else {
+            return true;
+        };
+
+        if let Some(pos_px) = &mut self.inner_position_pixels {
+            clamp_pos_to_monitor(egui_zoom_factor, &monitor, inner_size_points, pos_px);
+        }
+        if let Some(pos_px) = &mut self.outer_position_pixels {
+            clamp_pos_to_monitor(egui_zoom_factor, &monitor, inner_size_points, pos_px);
+        }
+
+        true
+    }
+
     pub fn clamp_position_to_monitors(
         &mut self,
         egui_zoom_factor: f32,
@@ -197,6 +245,21 @@ fn clamp_pos_to_monitors(
         return; // no monitors 🤷
     };
 
+    clamp_pos_to_monitor(
+        egui_zoom_factor,
+        &active_monitor,
+        window_size_pts,
+        position_px,
+    );
+}
+
+/// Move the window so that it is fully inside the given monitor, if possible.
+fn clamp_pos_to_monitor(
+    egui_zoom_factor: f32,
+    active_monitor: &winit::monitor::MonitorHandle,
+    window_size_pts: egui::Vec2,
+    position_px: &mut egui::Pos2,
+) {
     let mut window_size_px =
         window_size_pts * (egui_zoom_factor * active_monitor.scale_factor() as f32);
     // Add size of title bar. This is 32 px by default in Win 10/11.
@@ -45,6 +45,12 @@ impl CollapsingState {
         ctx.data_mut(|d| d.remove::<InnerState>(self.id));
     }
 
+    /// Forget the open/closed state of all collapsing headers and windows in `data`,
+    /// e.g. [`crate::Memory::data`].
+    pub fn remove_all(data: &mut crate::util::IdTypeMap) {
+        data.remove_by_type::<InnerState>();
+    }
+
     pub fn id(&self) -> Id {
         self.id
     }
@@ -281,7 +281,7 @@ pub struct ViewportBuilder {
     /// `eframe` will use this as the title of the native window.
     pub title: Option<String>,
 
-    /// This is wayland only. See [`Self::with_app_id`].
+    /// The application ID on Wayland, and the `WM_CLASS` on X11. See [`Self::with_app_id`].
     pub app_id: Option<String>,
     pub app_instance: Option<String>,
     pub layer_surface: Option<ViewportLayer>,
@@ -545,6 +545,9 @@ impl ViewportBuilder {
     /// Sets whether clamp the window's size to monitor's size. The default is `true` on linux, otherwise it is `false`.
     ///
     /// Note: On some Linux systems, a window size larger than the monitor causes crashes
+    ///
+    /// In eframe this also clamps [`Self::min_inner_size`] and [`Self::max_inner_size`]
+    /// of the root viewport, so the window always fits on the largest monitor.
     #[inline]
     pub fn with_clamp_size_to_monitor_size(mut self, value: bool) -> Self {
         self.clamp_size_to_monitor_size = Some(value);
@@ -621,6 +624,10 @@ impl ViewportBuilder {
     ///
     /// [xdg-shell]: https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_app_id
     ///
+    /// ### On X11
+    /// On X11 this sets the `WM_CLASS` of the window,
+    /// with [`Self::with_app_instance`] as the instance name.
+    ///
     /// ### eframe
     /// On eframe, the `app_id` of the root window is also used to determine
     /// the storage location of persistence files.
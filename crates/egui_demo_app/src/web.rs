@@ -50,6 +50,18 @@ impl WebHandle {
         self.runner.destroy();
     }
 
+    /// Stop running the app, e.g. while the tab is hidden.
+    #[wasm_bindgen]
+    pub fn pause(&self) {
+        self.runner.pause();
+    }
+
+    /// Continue running the app after [`Self::pause`].
+    #[wasm_bindgen]
+    pub fn resume(&self) {
+        self.runner.resume();
+    }
+
     /// Example on how to call into your app from JavaScript.
     #[wasm_bindgen]
     pub fn example(&self) {
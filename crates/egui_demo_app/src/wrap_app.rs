@@ -331,6 +331,11 @@ impl eframe::App for WrapApp {
     fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
         Some(&mut *self)
     }
+
+    #[cfg(target_arch = "wasm32")]
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(&*self)
+    }
 }
 
 impl WrapApp {
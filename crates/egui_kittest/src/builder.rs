@@ -183,7 +183,7 @@ impl<State> HarnessBuilder<State> {
     {
         let ctx = egui::Context::default();
 
-        let mut cc = eframe::CreationContext::_new_kittest(ctx.clone());
+        let mut cc = eframe::CreationContext::new_for_tests(ctx.clone());
         let mut frame = eframe::Frame::_new_kittest();
 
         self.renderer.setup_eframe(&mut cc, &mut frame);
@@ -0,0 +1,222 @@
+use crate::{Pos2, Rect, Rot2, Vec2, vec2};
+
+/// A 2D affine transform: any combination of translation, rotation, scaling and shearing.
+///
+/// Stored as a 3x2 matrix: the images of the X and Y axes, followed by a translation.
+///
+/// Angles are clockwise in radians, just like in [`Rot2`],
+/// so a 𝞃/4 = 90° rotation maps the X axis to the Y axis.
+///
+/// For the common case of only translation and uniform scaling, see [`crate::TSTransform`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Affine2 {
+    /// Where the X axis ends up (before translation).
+    pub x_axis: Vec2,
+
+    /// Where the Y axis ends up (before translation).
+    pub y_axis: Vec2,
+
+    /// Translation, applied last.
+    pub translation: Vec2,
+}
+
+impl Eq for Affine2 {}
+
+impl Default for Affine2 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    pub const IDENTITY: Self = Self {
+        x_axis: Vec2::X,
+        y_axis: Vec2::Y,
+        translation: Vec2::ZERO,
+    };
+
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Rotate around the origin by this clockwise angle (in radians).
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        let rot = Rot2::from_angle(angle);
+        Self {
+            x_axis: rot * Vec2::X,
+            y_axis: rot * Vec2::Y,
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// Scale around the origin, separately in each axis.
+    #[inline]
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self {
+            x_axis: vec2(scale.x, 0.0),
+            y_axis: vec2(0.0, scale.y),
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// First scale, then rotate (both around the origin), then translate.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Affine2};
+    /// let affine = Affine2::from_scale_angle_translation(vec2(2.0, 3.0), 0.0, vec2(10.0, 20.0));
+    /// assert_eq!(affine.transform_pos2(pos2(1.0, 1.0)), pos2(12.0, 23.0));
+    /// ```
+    #[inline]
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+        let rot = Rot2::from_angle(angle);
+        Self {
+            x_axis: rot * vec2(scale.x, 0.0),
+            y_axis: rot * vec2(0.0, scale.y),
+            translation,
+        }
+    }
+
+    /// Transform a position, including the translation.
+    #[inline]
+    pub fn transform_pos2(&self, pos: Pos2) -> Pos2 {
+        (self.transform_vec2(pos.to_vec2()) + self.translation).to_pos2()
+    }
+
+    /// Transform a direction or size, ignoring the translation.
+    #[inline]
+    pub fn transform_vec2(&self, vec: Vec2) -> Vec2 {
+        vec.x * self.x_axis + vec.y * self.y_axis
+    }
+
+    /// The determinant of the linear part: how much areas are scaled.
+    ///
+    /// Zero if the transform flattens everything onto a line or point, and can't be inverted.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+    }
+
+    /// Is this a valid, invertible transform?
+    pub fn is_valid(&self) -> bool {
+        self.x_axis.is_finite()
+            && self.y_axis.is_finite()
+            && self.translation.is_finite()
+            && self.determinant() != 0.0
+    }
+
+    /// The transform that undoes this one.
+    ///
+    /// The result is non-finite if this transform is not [valid](Self::is_valid).
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Affine2};
+    /// let affine = Affine2::from_scale_angle_translation(vec2(2.0, 4.0), 0.0, vec2(1.0, 2.0));
+    /// let p = pos2(3.0, 6.0);
+    /// assert_eq!(affine.inverse().transform_pos2(affine.transform_pos2(p)), p);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let inv_det = 1.0 / self.determinant();
+        let x_axis = inv_det * vec2(self.y_axis.y, -self.x_axis.y);
+        let y_axis = inv_det * vec2(-self.y_axis.x, self.x_axis.x);
+        let translation = -(self.translation.x * x_axis + self.translation.y * y_axis);
+        Self {
+            x_axis,
+            y_axis,
+            translation,
+        }
+    }
+}
+
+/// Transforms the position.
+impl std::ops::Mul<Pos2> for Affine2 {
+    type Output = Pos2;
+
+    #[inline]
+    fn mul(self, pos: Pos2) -> Pos2 {
+        self.transform_pos2(pos)
+    }
+}
+
+impl std::ops::Mul<Self> for Affine2 {
+    type Output = Self;
+
+    /// Applies the right hand side transform, then the left hand side.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Affine2};
+    /// let scale = Affine2::from_scale(vec2(2.0, 2.0));
+    /// let translate = Affine2::from_translation(vec2(1.0, 0.0));
+    /// assert_eq!((translate * scale) * pos2(1.0, 1.0), pos2(3.0, 2.0));
+    /// assert_eq!((scale * translate) * pos2(1.0, 1.0), pos2(4.0, 2.0));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            x_axis: self.transform_vec2(rhs.x_axis),
+            y_axis: self.transform_vec2(rhs.y_axis),
+            translation: self.transform_pos2(rhs.translation.to_pos2()).to_vec2(),
+        }
+    }
+}
+
+impl Rect {
+    /// The axis-aligned bounding box of this rectangle after applying the transform.
+    ///
+    /// ```
+    /// # use emath::{pos2, Affine2, Rect};
+    /// let rect = Rect::from_min_max(pos2(-1.0, -1.0), pos2(1.0, 1.0));
+    /// let rotated = rect.transform_by(&Affine2::from_angle(std::f32::consts::TAU / 8.0));
+    /// assert!((rotated.max.x - std::f32::consts::SQRT_2).abs() < 1e-5);
+    /// ```
+    #[must_use]
+    pub fn transform_by(self, affine: &Affine2) -> Self {
+        Self::from_points(&[
+            affine.transform_pos2(self.left_top()),
+            affine.transform_pos2(self.right_top()),
+            affine.transform_pos2(self.left_bottom()),
+            affine.transform_pos2(self.right_bottom()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pos2;
+
+    fn assert_pos_eq(a: Pos2, b: Pos2) {
+        assert!((a - b).length() < 1e-4, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_affine2() {
+        use std::f32::consts::TAU;
+
+        let rotate = Affine2::from_angle(TAU / 4.0);
+        assert_pos_eq(rotate * pos2(1.0, 0.0), pos2(0.0, 1.0));
+
+        let affine = Affine2::from_translation(vec2(5.0, -3.0))
+            * Affine2::from_angle(1.0)
+            * Affine2::from_scale(vec2(2.0, 0.5));
+        let combined = Affine2::from_scale_angle_translation(vec2(2.0, 0.5), 1.0, vec2(5.0, -3.0));
+
+        for p in [pos2(0.0, 0.0), pos2(1.0, 2.0), pos2(-7.5, 3.25)] {
+            assert_pos_eq(affine * p, combined * p);
+            assert_pos_eq((affine.inverse() * affine) * p, p);
+            assert_pos_eq(affine.inverse() * (affine * p), p);
+        }
+
+        assert!(affine.is_valid());
+        assert!(!Affine2::from_scale(vec2(1.0, 0.0)).is_valid());
+    }
+}
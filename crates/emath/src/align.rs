@@ -30,6 +30,9 @@ impl Align {
     /// Convenience for [`Self::Max`]
     pub const BOTTOM: Self = Self::Max;
 
+    /// All alignments, in order: `[Min, Center, Max]`.
+    pub const ALL: [Self; 3] = [Self::Min, Self::Center, Self::Max];
+
     /// Convert `Min => 0.0`, `Center => 0.5` or `Max => 1.0`.
     #[inline(always)]
     pub fn to_factor(self) -> f32 {
@@ -141,6 +144,82 @@ impl Align {
             Self::Max => Rangef::new(max - size, max),
         }
     }
+
+    /// Like [`Self::align_size_within_range`], but the returned range never exceeds the given one.
+    ///
+    /// If `size` is bigger than the range, it is shrunk to fit.
+    /// If one bound of the range is infinite, the returned range is placed against the finite bound.
+    ///
+    /// ```
+    /// use std::f32::{INFINITY, NEG_INFINITY};
+    /// use emath::Align::*;
+    ///
+    /// // The size is smaller than a range - same as `align_size_within_range`
+    /// assert_eq!(Min   .align_size_within_range_clamped(2.0, 10.0..=20.0), 10.0..=12.0);
+    /// assert_eq!(Center.align_size_within_range_clamped(2.0, 10.0..=20.0), 14.0..=16.0);
+    /// assert_eq!(Max   .align_size_within_range_clamped(2.0, 10.0..=20.0), 18.0..=20.0);
+    ///
+    /// // The size is bigger than a range
+    /// assert_eq!(Min   .align_size_within_range_clamped(20.0, 10.0..=20.0), 10.0..=20.0);
+    /// assert_eq!(Center.align_size_within_range_clamped(20.0, 10.0..=20.0), 10.0..=20.0);
+    /// assert_eq!(Max   .align_size_within_range_clamped(INFINITY, 10.0..=20.0), 10.0..=20.0);
+    ///
+    /// // One infinite bound
+    /// assert_eq!(Min   .align_size_within_range_clamped(2.0, 10.0..=INFINITY),     10.0..=12.0);
+    /// assert_eq!(Center.align_size_within_range_clamped(2.0, 10.0..=INFINITY),     10.0..=12.0);
+    /// assert_eq!(Max   .align_size_within_range_clamped(2.0, NEG_INFINITY..=20.0), 18.0..=20.0);
+    /// assert_eq!(Min   .align_size_within_range_clamped(INFINITY, 10.0..=INFINITY), 10.0..=INFINITY);
+    /// ```
+    #[inline]
+    pub fn align_size_within_range_clamped(self, size: f32, range: impl Into<Rangef>) -> Rangef {
+        let range = range.into();
+        let Rangef { min, max } = range;
+        let size = size.min(range.span()).max(0.0);
+
+        if size == f32::INFINITY {
+            return range;
+        }
+
+        match (min.is_finite(), max.is_finite()) {
+            (true, false) => Rangef::new(min, min + size),
+            (false, true) => Rangef::new(max - size, max),
+            _ => self.align_size_within_range(size, range),
+        }
+    }
+
+    /// Split the range into `n` contiguous sub-ranges of equal size, from `min` to `max`.
+    ///
+    /// Each sub-range starts exactly where the previous one ended,
+    /// and the first and last sub-ranges start and end exactly at the bounds of `range`.
+    /// Yields nothing if `n` is zero.
+    ///
+    /// ```
+    /// use emath::{Align, Rangef};
+    /// let slots: Vec<Rangef> = Align::split_range(Rangef::new(0.0, 30.0), 3).collect();
+    /// assert_eq!(slots, [Rangef::new(0.0, 10.0), Rangef::new(10.0, 20.0), Rangef::new(20.0, 30.0)]);
+    /// ```
+    pub fn split_range(range: impl Into<Rangef>, n: usize) -> impl Iterator<Item = Rangef> {
+        let range = range.into();
+        let boundary = move |i: usize| crate::lerp(range.min..=range.max, i as f32 / n as f32);
+        (0..n).map(move |i| Rangef::new(boundary(i), boundary(i + 1)))
+    }
+
+    /// Split the range into `n` equal slots using [`Self::split_range`],
+    /// and align `size` within each slot using [`Self::align_size_within_range`].
+    ///
+    /// ```
+    /// use emath::{Align, Rangef};
+    /// let buttons: Vec<Rangef> = Align::Center.align_size_within_split_range(4.0, 0.0..=20.0, 2).collect();
+    /// assert_eq!(buttons, [Rangef::new(3.0, 7.0), Rangef::new(13.0, 17.0)]);
+    /// ```
+    pub fn align_size_within_split_range(
+        self,
+        size: f32,
+        range: impl Into<Rangef>,
+        n: usize,
+    ) -> impl Iterator<Item = Rangef> {
+        Self::split_range(range, n).map(move |slot| self.align_size_within_range(size, slot))
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -160,6 +239,27 @@ impl Align2 {
     pub const RIGHT_BOTTOM: Self = Self([Align::Max, Align::Max]);
     pub const RIGHT_CENTER: Self = Self([Align::Max, Align::Center]);
     pub const RIGHT_TOP: Self = Self([Align::Max, Align::Min]);
+
+    /// All nine alignments, top-to-bottom, then left-to-right within each row.
+    ///
+    /// ```
+    /// # use emath::Align2;
+    /// assert_eq!(Align2::ALL[0], Align2::LEFT_TOP);
+    /// assert_eq!(Align2::ALL[1], Align2::CENTER_TOP);
+    /// assert_eq!(Align2::ALL[3], Align2::LEFT_CENTER);
+    /// assert_eq!(Align2::ALL[8], Align2::RIGHT_BOTTOM);
+    /// ```
+    pub const ALL: [Self; 9] = [
+        Self::LEFT_TOP,
+        Self::CENTER_TOP,
+        Self::RIGHT_TOP,
+        Self::LEFT_CENTER,
+        Self::CENTER_CENTER,
+        Self::RIGHT_CENTER,
+        Self::LEFT_BOTTOM,
+        Self::CENTER_BOTTOM,
+        Self::RIGHT_BOTTOM,
+    ];
 }
 
 impl Align2 {
@@ -180,6 +280,34 @@ impl Align2 {
         vec2(self.x().to_sign(), self.y().to_sign())
     }
 
+    /// 0.0, 0.5, or 1.0 for each axis, e.g. `rect.min + rect.size() * align.to_factor2()`.
+    ///
+    /// ```
+    /// # use emath::{vec2, Align2};
+    /// assert_eq!(Align2::LEFT_TOP.to_factor2(), vec2(0.0, 0.0));
+    /// assert_eq!(Align2::CENTER_BOTTOM.to_factor2(), vec2(0.5, 1.0));
+    /// ```
+    pub fn to_factor2(self) -> Vec2 {
+        vec2(self.x().to_factor(), self.y().to_factor())
+    }
+
+    /// Smoothly interpolate between two alignments, e.g. to animate an anchor.
+    ///
+    /// Returns a factor a fraction `t` of the way from `self.to_factor2()` to `other.to_factor2()`.
+    /// At `t = 0.0` this is exactly `self.to_factor2()`, and at `t = 1.0` exactly `other.to_factor2()`.
+    ///
+    /// Use the result with [`align_size_within_rect_factor`] or [`Rect::lerp_inside`].
+    ///
+    /// ```
+    /// # use emath::{vec2, Align2};
+    /// assert_eq!(Align2::LEFT_TOP.blend(Align2::RIGHT_BOTTOM, 0.0), vec2(0.0, 0.0));
+    /// assert_eq!(Align2::LEFT_TOP.blend(Align2::RIGHT_BOTTOM, 0.25), vec2(0.25, 0.25));
+    /// assert_eq!(Align2::LEFT_TOP.blend(Align2::RIGHT_CENTER, 1.0), vec2(1.0, 0.5));
+    /// ```
+    pub fn blend(self, other: Self, t: f32) -> Vec2 {
+        crate::lerp(self.to_factor2()..=other.to_factor2(), t)
+    }
+
     /// Flip on the x-axis
     /// e.g. `TOP_LEFT` -> `TOP_RIGHT`
     pub fn flip_x(self) -> Self {
@@ -272,6 +400,22 @@ impl Align2 {
 
         pos2(x, y)
     }
+
+    /// Like [`Self::pos_in_rect`], but first shrinks the frame by `margin` on each side.
+    ///
+    /// Useful for placing e.g. a badge near a corner without touching the edges.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Align2, Rect};
+    /// let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+    /// let margin = vec2(4.0, 2.0);
+    /// assert_eq!(Align2::LEFT_TOP.pos_in_rect_with_margin(&frame, margin), pos2(4.0, 2.0));
+    /// assert_eq!(Align2::RIGHT_BOTTOM.pos_in_rect_with_margin(&frame, margin), pos2(96.0, 48.0));
+    /// assert_eq!(Align2::CENTER_CENTER.pos_in_rect_with_margin(&frame, margin), pos2(50.0, 25.0));
+    /// ```
+    pub fn pos_in_rect_with_margin(self, frame: &Rect, margin: Vec2) -> Pos2 {
+        self.pos_in_rect(&frame.shrink2(margin))
+    }
 }
 
 impl std::ops::Index<usize> for Align2 {
@@ -299,6 +443,48 @@ pub fn center_size_in_rect(size: Vec2, frame: Rect) -> Rect {
     Align2::CENTER_CENTER.align_size_within_rect(size, frame)
 }
 
+/// Like [`Align2::align_size_within_rect`], but with a continuous alignment factor for each axis,
+/// e.g. from [`Align2::blend`].
+///
+/// A factor of `0.0` places `size` against the left/top of `frame`, and `1.0` against the right/bottom.
+/// At those endpoints the result is the same as for [`Align::Min`] and [`Align::Max`].
+///
+/// ```
+/// # use emath::{align::align_size_within_rect_factor, pos2, vec2, Align2, Rect};
+/// let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+/// let size = vec2(20.0, 10.0);
+/// assert_eq!(
+///     align_size_within_rect_factor(Align2::RIGHT_BOTTOM.to_factor2(), size, frame),
+///     Align2::RIGHT_BOTTOM.align_size_within_rect(size, frame)
+/// );
+/// let halfway = Align2::LEFT_TOP.blend(Align2::RIGHT_BOTTOM, 0.5);
+/// assert_eq!(
+///     align_size_within_rect_factor(halfway, size, frame),
+///     Rect::from_min_size(pos2(40.0, 20.0), size)
+/// );
+/// ```
+pub fn align_size_within_rect_factor(factor: Vec2, size: Vec2, frame: Rect) -> Rect {
+    let x = crate::lerp(frame.min.x..=frame.max.x - size.x, factor.x);
+    let y = crate::lerp(frame.min.y..=frame.max.y - size.y, factor.y);
+    Rect::from_min_size(pos2(x, y), size)
+}
+
+/// The point a fraction `t` of the way from `a.pos_in_rect(frame)` to `b.pos_in_rect(frame)`.
+///
+/// `t = 0.0` gives the point of `a` and `t = 1.0` the point of `b`,
+/// using the same corner definitions as [`Align2::pos_in_rect`].
+///
+/// ```
+/// # use emath::{align::lerp_align2_in_rect, pos2, Align2, Rect};
+/// let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+/// assert_eq!(lerp_align2_in_rect(Align2::LEFT_TOP, Align2::RIGHT_BOTTOM, &frame, 0.0), pos2(0.0, 0.0));
+/// assert_eq!(lerp_align2_in_rect(Align2::LEFT_TOP, Align2::RIGHT_BOTTOM, &frame, 0.5), pos2(50.0, 25.0));
+/// assert_eq!(lerp_align2_in_rect(Align2::LEFT_TOP, Align2::RIGHT_TOP, &frame, 0.25), pos2(25.0, 0.0));
+/// ```
+pub fn lerp_align2_in_rect(a: Align2, b: Align2, frame: &Rect, t: f32) -> Pos2 {
+    frame.lerp_inside(crate::lerp(a.to_factor2()..=b.to_factor2(), t))
+}
+
 impl std::fmt::Debug for Align2 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Align2({:?}, {:?})", self.x(), self.y())
@@ -3,7 +3,7 @@ use std::{
     ops::{Add, AddAssign, MulAssign, Sub, SubAssign},
 };
 
-use crate::{Div, Mul, Vec2, lerp};
+use crate::{Div, Mul, Rect, Vec2, lerp};
 
 /// A position on screen.
 ///
@@ -199,6 +199,74 @@ impl Pos2 {
             y: lerp(self.y..=other.y, t),
         }
     }
+
+    /// Rotate this point around `pivot` by the given CW angle (in radians).
+    ///
+    /// See [`Vec2::rotate`].
+    #[must_use]
+    #[inline]
+    pub fn rotate_around(self, pivot: Self, angle: f32) -> Self {
+        pivot + crate::Rot2::from_angle(angle) * (self - pivot)
+    }
+
+    /// The point on the line segment from `a` to `b` that is closest to `self`.
+    ///
+    /// ```
+    /// # use emath::pos2;
+    /// let (a, b) = (pos2(0.0, 0.0), pos2(10.0, 0.0));
+    /// assert_eq!(pos2(3.0, 5.0).closest_point_on_segment(a, b), pos2(3.0, 0.0));
+    /// assert_eq!(pos2(-3.0, 5.0).closest_point_on_segment(a, b), a);
+    /// assert_eq!(pos2(13.0, -5.0).closest_point_on_segment(a, b), b);
+    /// ```
+    #[must_use]
+    pub fn closest_point_on_segment(self, a: Self, b: Self) -> Self {
+        let ab = b - a;
+        let length_sq = ab.length_sq();
+        if length_sq <= 0.0 {
+            return a;
+        }
+        let t = ((self - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+        a + t * ab
+    }
+
+    /// The point on the border of `rect` that is closest to `self`.
+    ///
+    /// Unlike [`Rect::clamp`], this also moves points inside the rectangle onto the closest edge.
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+    ///
+    /// // Above, to the left and right:
+    /// assert_eq!(pos2(-2.0, -3.0).closest_point_on_rect_perimeter(rect), pos2(0.0, 0.0));
+    /// assert_eq!(pos2(4.0, -3.0).closest_point_on_rect_perimeter(rect), pos2(4.0, 0.0));
+    /// assert_eq!(pos2(12.0, -3.0).closest_point_on_rect_perimeter(rect), pos2(10.0, 0.0));
+    ///
+    /// // Level with the rectangle:
+    /// assert_eq!(pos2(-2.0, 6.0).closest_point_on_rect_perimeter(rect), pos2(0.0, 6.0));
+    /// assert_eq!(pos2(12.0, 6.0).closest_point_on_rect_perimeter(rect), pos2(10.0, 6.0));
+    ///
+    /// // Below, to the left and right:
+    /// assert_eq!(pos2(-2.0, 13.0).closest_point_on_rect_perimeter(rect), pos2(0.0, 10.0));
+    /// assert_eq!(pos2(4.0, 13.0).closest_point_on_rect_perimeter(rect), pos2(4.0, 10.0));
+    /// assert_eq!(pos2(12.0, 13.0).closest_point_on_rect_perimeter(rect), pos2(10.0, 10.0));
+    ///
+    /// // Inside, closest to the right edge:
+    /// assert_eq!(pos2(8.0, 4.0).closest_point_on_rect_perimeter(rect), pos2(10.0, 4.0));
+    /// ```
+    #[must_use]
+    pub fn closest_point_on_rect_perimeter(self, rect: Rect) -> Self {
+        [
+            (rect.left_top(), rect.right_top()),
+            (rect.right_top(), rect.right_bottom()),
+            (rect.right_bottom(), rect.left_bottom()),
+            (rect.left_bottom(), rect.left_top()),
+        ]
+        .into_iter()
+        .map(|(a, b)| self.closest_point_on_segment(a, b))
+        .min_by(|p, q| self.distance_sq(*p).total_cmp(&self.distance_sq(*q)))
+        .unwrap_or(self)
+    }
 }
 
 impl std::ops::Index<usize> for Pos2 {
@@ -140,6 +140,127 @@ impl Rangef {
     pub fn intersects(self, other: Self) -> bool {
         other.min <= self.max && self.min <= other.max
     }
+
+    /// Like [`Self::intersection`], but returns `None` if the ranges don't intersect.
+    ///
+    /// Ranges that only touch intersect in a single point.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let a = Rangef::new(0.0, 10.0);
+    /// assert_eq!(a.checked_intersection(Rangef::new(5.0, 15.0)), Some(Rangef::new(5.0, 10.0)));
+    /// assert_eq!(a.checked_intersection(Rangef::new(10.0, 20.0)), Some(Rangef::point(10.0)));
+    /// assert_eq!(a.checked_intersection(Rangef::new(20.0, 30.0)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_intersection(self, other: Self) -> Option<Self> {
+        self.intersects(other).then(|| self.intersection(other))
+    }
+
+    /// Do the two ranges share more than a single point?
+    ///
+    /// Unlike [`Self::intersects`], ranges that only touch at their ends do not overlap.
+    /// A point-sized range overlaps a range if it lies strictly inside it.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let a = Rangef::new(0.0, 10.0);
+    /// assert!(a.overlaps(Rangef::new(5.0, 15.0)));
+    /// assert!(!a.overlaps(Rangef::new(10.0, 20.0)));
+    /// assert!(a.intersects(Rangef::new(10.0, 20.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overlaps(self, other: Self) -> bool {
+        other.min < self.max && self.min < other.max
+    }
+
+    /// Is all of `other` inside of this range?
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let a = Rangef::new(0.0, 10.0);
+    /// assert!(a.contains_range(Rangef::new(2.0, 8.0)));
+    /// assert!(a.contains_range(a));
+    /// assert!(!a.contains_range(Rangef::new(5.0, 15.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_range(self, other: Self) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+
+    /// The smallest range that contains both ranges, including any gap between them.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// assert_eq!(Rangef::new(0.0, 1.0).union(Rangef::new(5.0, 6.0)), Rangef::new(0.0, 6.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Evenly spaced values starting at `min`, `step` apart, up to and including `max`.
+    ///
+    /// If `min > max` the values go in the negative direction,
+    /// so only the magnitude of `step` matters.
+    /// Each value is computed from `min` directly, so errors do not accumulate.
+    ///
+    /// If `step` is zero or not finite, only `min` is yielded.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let values: Vec<f32> = Rangef::new(0.0, 1.0).iter_steps(0.25).collect();
+    /// assert_eq!(values, [0.0, 0.25, 0.5, 0.75, 1.0]);
+    ///
+    /// let values: Vec<f32> = Rangef::new(1.0, 0.0).iter_steps(0.5).collect();
+    /// assert_eq!(values, [1.0, 0.5, 0.0]);
+    /// ```
+    pub fn iter_steps(self, step: f32) -> impl Iterator<Item = f32> {
+        let min = self.min as f64;
+        let span = self.span() as f64;
+        let step = step.abs() as f64;
+
+        let count = if step == 0.0 || !step.is_finite() {
+            1
+        } else {
+            let num_steps = span.abs() / step;
+            // Allow for some rounding error, so that e.g. `0.0..=1.0` in steps of `0.1` includes `1.0`:
+            (num_steps + num_steps.max(1.0) * 1e-6).floor() as usize + 1
+        };
+        let step = step.copysign(span);
+        let bounds = self.as_positive();
+
+        (0..count).map(move |i| bounds.clamp((min + i as f64 * step) as f32))
+    }
+
+    /// Exactly `n` evenly spaced values from `min` to `max`, including both endpoints.
+    ///
+    /// If `n == 1`, only `min` is yielded.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let values: Vec<f32> = Rangef::new(0.0, 1.0).iter_n_steps(3).collect();
+    /// assert_eq!(values, [0.0, 0.5, 1.0]);
+    ///
+    /// let values: Vec<f32> = Rangef::new(2.0, -2.0).iter_n_steps(5).collect();
+    /// assert_eq!(values, [2.0, 1.0, 0.0, -1.0, -2.0]);
+    /// ```
+    pub fn iter_n_steps(self, n: usize) -> impl Iterator<Item = f32> {
+        let (min, max) = (self.min as f64, self.max as f64);
+        let last = n.saturating_sub(1).max(1) as f64;
+
+        (0..n).map(move |i| {
+            let t = i as f64 / last;
+            (min * (1.0 - t) + max * t) as f32
+        })
+    }
 }
 
 impl From<Rangef> for RangeInclusive<f32> {
@@ -218,3 +339,108 @@ impl PartialEq<Rangef> for RangeInclusive<f32> {
         *self.start() == other.min && *self.end() == other.max
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_steps_fractional() {
+        let values: Vec<f32> = Rangef::new(0.0, 1.0).iter_steps(0.1).collect();
+        assert_eq!(values.len(), 11);
+        for (i, value) in values.iter().enumerate() {
+            assert!((value - i as f32 * 0.1).abs() < 1e-6, "{value} at {i}");
+        }
+        assert_eq!(values.last(), Some(&1.0));
+
+        let values: Vec<f32> = Rangef::new(0.0, 1.0).iter_steps(0.3).collect();
+        assert_eq!(values.len(), 4);
+        assert!(values.iter().all(|&value| value <= 1.0));
+
+        let values: Vec<f32> = Rangef::new(1.0, 0.0).iter_steps(-0.25).collect();
+        assert_eq!(values, [1.0, 0.75, 0.5, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn test_iter_steps_zero_length() {
+        let values: Vec<f32> = Rangef::point(3.0).iter_steps(0.5).collect();
+        assert_eq!(values, [3.0]);
+
+        let values: Vec<f32> = Rangef::new(0.0, 1.0).iter_steps(0.0).collect();
+        assert_eq!(values, [0.0]);
+
+        let values: Vec<f32> = Rangef::point(3.0).iter_n_steps(3).collect();
+        assert_eq!(values, [3.0, 3.0, 3.0]);
+
+        assert_eq!(Rangef::new(0.0, 1.0).iter_n_steps(0).count(), 0);
+        let values: Vec<f32> = Rangef::new(0.0, 1.0).iter_n_steps(1).collect();
+        assert_eq!(values, [0.0]);
+    }
+
+    #[test]
+    fn test_range_set_operations() {
+        let a = Rangef::new(0.0, 10.0);
+        let b = Rangef::new(5.0, 15.0);
+        let far = Rangef::new(20.0, 30.0);
+        let touching = Rangef::new(10.0, 20.0);
+
+        assert!(a.overlaps(b) && b.overlaps(a));
+        assert!(!a.overlaps(far) && !far.overlaps(a));
+        assert!(!a.overlaps(touching) && !touching.overlaps(a));
+
+        assert_eq!(a.checked_intersection(b), Some(Rangef::new(5.0, 10.0)));
+        assert_eq!(a.checked_intersection(touching), Some(Rangef::point(10.0)));
+        assert_eq!(a.checked_intersection(far), None);
+
+        assert_eq!(a.union(b), Rangef::new(0.0, 15.0));
+        assert_eq!(a.union(far), Rangef::new(0.0, 30.0));
+        assert_eq!(far.union(a), a.union(far));
+
+        assert!(a.contains_range(Rangef::new(2.0, 8.0)));
+        assert!(a.contains_range(a));
+        assert!(!a.contains_range(b));
+        assert!(!Rangef::new(2.0, 8.0).contains_range(a));
+    }
+
+    #[test]
+    fn test_range_set_operations_degenerate() {
+        let a = Rangef::new(0.0, 10.0);
+        let inside = Rangef::point(5.0);
+        let at_edge = Rangef::point(10.0);
+        let outside = Rangef::point(20.0);
+
+        assert!(a.overlaps(inside) && inside.overlaps(a));
+        assert!(!a.overlaps(at_edge));
+        assert!(!a.overlaps(outside));
+        assert!(!inside.overlaps(inside));
+
+        assert!(a.contains_range(inside));
+        assert!(a.contains_range(at_edge));
+        assert!(!a.contains_range(outside));
+        assert!(inside.contains_range(inside));
+        assert!(!inside.contains_range(a));
+
+        assert_eq!(a.checked_intersection(inside), Some(inside));
+        assert_eq!(a.checked_intersection(at_edge), Some(at_edge));
+        assert_eq!(a.checked_intersection(outside), None);
+        assert_eq!(inside.checked_intersection(inside), Some(inside));
+
+        assert_eq!(a.union(outside), Rangef::new(0.0, 20.0));
+        assert_eq!(inside.union(inside), inside);
+        assert_eq!(inside.union(Rangef::point(-5.0)), Rangef::new(-5.0, 5.0));
+    }
+
+    #[test]
+    fn test_iter_steps_large_range() {
+        // Accumulating `0.1` in `f32` would drift far away from the true values over this many steps:
+        let range = Rangef::new(0.0, 100_000.0);
+        let values: Vec<f32> = range.iter_steps(0.1).collect();
+        assert_eq!(values.len(), 1_000_001);
+        assert_eq!(values[500_000], 50_000.0);
+        assert_eq!(values.last(), Some(&100_000.0));
+
+        let range = Rangef::new(-1e9, 1e9);
+        let values: Vec<f32> = range.iter_n_steps(5).collect();
+        assert_eq!(values, [-1e9, -5e8, 0.0, 5e8, 1e9]);
+    }
+}
@@ -119,6 +119,45 @@ impl Rect {
         }
     }
 
+    /// Scale `size` uniformly to the largest size that fits inside `container`, and center it there.
+    ///
+    /// Like `object-fit: contain` in CSS.
+    /// If `size` has no area, the result is a zero-sized rect at the center of `container`.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Rect};
+    /// let container = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0));
+    /// let fitted = Rect::fit_in_rect(vec2(20.0, 20.0), container);
+    /// assert_eq!(fitted, Rect::from_min_size(pos2(25.0, 0.0), vec2(50.0, 50.0)));
+    /// ```
+    pub fn fit_in_rect(size: Vec2, container: Self) -> Self {
+        let scale = (container.width() / size.x).min(container.height() / size.y);
+        Self::scaled_in_center(size, scale, container)
+    }
+
+    /// Scale `size` uniformly to the smallest size that covers all of `container`, and center it there.
+    ///
+    /// Like `object-fit: cover` in CSS.
+    /// If `size` has no area, the result is a zero-sized rect at the center of `container`.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Rect};
+    /// let container = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0));
+    /// let filled = Rect::fill_rect(vec2(20.0, 20.0), container);
+    /// assert_eq!(filled, Rect::from_min_size(pos2(0.0, -25.0), vec2(100.0, 100.0)));
+    /// ```
+    pub fn fill_rect(size: Vec2, container: Self) -> Self {
+        let scale = (container.width() / size.x).max(container.height() / size.y);
+        Self::scaled_in_center(size, scale, container)
+    }
+
+    fn scaled_in_center(size: Vec2, scale: f32, container: Self) -> Self {
+        if size.x <= 0.0 || size.y <= 0.0 || !scale.is_finite() {
+            return Self::from_pos(container.center());
+        }
+        Self::from_center_size(container.center(), scale * size)
+    }
+
     /// Bounding-box around the points.
     pub fn from_points(points: &[Pos2]) -> Self {
         let mut rect = Self::NOTHING;
@@ -224,6 +263,90 @@ impl Rect {
         Self::from_min_max(self.min + amnt, self.max - amnt)
     }
 
+    /// Move each edge inwards by this much. Same as [`Self::shrink`].
+    #[inline]
+    #[must_use]
+    pub fn inset(self, amount: f32) -> Self {
+        self.shrink(amount)
+    }
+
+    /// Move each edge outwards by this much. Same as [`Self::expand`].
+    #[inline]
+    #[must_use]
+    pub fn outset(self, amount: f32) -> Self {
+        self.expand(amount)
+    }
+
+    /// Move each edge inwards by its own amount, like CSS padding.
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+    /// assert_eq!(
+    ///     rect.inset_sides(1.0, 2.0, 3.0, 4.0),
+    ///     Rect::from_min_max(pos2(1.0, 3.0), pos2(98.0, 46.0))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn inset_sides(self, left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self::from_min_max(
+            pos2(self.min.x + left, self.min.y + top),
+            pos2(self.max.x - right, self.max.y - bottom),
+        )
+    }
+
+    /// Move each edge outwards by its own amount. The inverse of [`Self::inset_sides`].
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+    /// assert_eq!(rect.inset_sides(1.0, 2.0, 3.0, 4.0).outset_sides(1.0, 2.0, 3.0, 4.0), rect);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn outset_sides(self, left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        self.inset_sides(-left, -right, -top, -bottom)
+    }
+
+    /// Snap each edge outwards to the physical pixel grid, so that the result contains `self`.
+    ///
+    /// The argument `pixels_per_point` is the number of _physical pixels_ per logical UI point.
+    /// To snap each edge to the _nearest_ pixel, use [`crate::GuiRounding::round_to_pixels`].
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.2, 0.6), pos2(1.2, 1.6));
+    /// assert_eq!(rect.ceil_to_pixels(2.0), Rect::from_min_max(pos2(0.0, 0.5), pos2(1.5, 2.0)));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ceil_to_pixels(self, pixels_per_point: f32) -> Self {
+        Self::from_min_max(
+            (self.min * pixels_per_point).floor() / pixels_per_point,
+            (self.max * pixels_per_point).ceil() / pixels_per_point,
+        )
+    }
+
+    /// Snap each edge inwards to the physical pixel grid, so that the result is contained in `self`.
+    ///
+    /// The argument `pixels_per_point` is the number of _physical pixels_ per logical UI point.
+    /// To snap each edge to the _nearest_ pixel, use [`crate::GuiRounding::round_to_pixels`].
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.2, 0.6), pos2(1.2, 1.6));
+    /// assert_eq!(rect.floor_to_pixels(2.0), Rect::from_min_max(pos2(0.5, 1.0), pos2(1.0, 1.5)));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn floor_to_pixels(self, pixels_per_point: f32) -> Self {
+        Self::from_min_max(
+            (self.min * pixels_per_point).ceil() / pixels_per_point,
+            (self.max * pixels_per_point).floor() / pixels_per_point,
+        )
+    }
+
     #[must_use]
     #[inline]
     pub fn translate(self, amnt: Vec2) -> Self {
@@ -646,6 +769,121 @@ impl Rect {
         let bottom = Self::from_min_max(Pos2::new(self.min.x, split_y), self.max);
         (top, bottom)
     }
+
+    /// Split rectangle into a left and a right part at the given `x` coordinate.
+    ///
+    /// `x` is clamped to the horizontal range of the rectangle,
+    /// so one of the parts may end up with zero width.
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 2.0));
+    /// let (left, right) = rect.split_at_x(1.0);
+    /// assert_eq!(left, Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 2.0)));
+    /// assert_eq!(right, Rect::from_min_max(pos2(1.0, 0.0), pos2(4.0, 2.0)));
+    ///
+    /// let (left, right) = rect.split_at_x(10.0);
+    /// assert_eq!(left, rect);
+    /// assert_eq!(right.width(), 0.0);
+    /// ```
+    #[inline]
+    pub fn split_at_x(self, x: f32) -> (Self, Self) {
+        self.split_left_right_at_x(x.clamp(self.min.x, self.max.x))
+    }
+
+    /// Split rectangle into a top and a bottom part at the given `y` coordinate.
+    ///
+    /// `y` is clamped to the vertical range of the rectangle,
+    /// so one of the parts may end up with zero height.
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(2.0, 4.0));
+    /// let (top, bottom) = rect.split_at_y(3.0);
+    /// assert_eq!(top, Rect::from_min_max(pos2(0.0, 0.0), pos2(2.0, 3.0)));
+    /// assert_eq!(bottom, Rect::from_min_max(pos2(0.0, 3.0), pos2(2.0, 4.0)));
+    ///
+    /// let (top, bottom) = rect.split_at_y(-1.0);
+    /// assert_eq!(top.height(), 0.0);
+    /// assert_eq!(bottom, rect);
+    /// ```
+    #[inline]
+    pub fn split_at_y(self, y: f32) -> (Self, Self) {
+        self.split_top_bottom_at_y(y.clamp(self.min.y, self.max.y))
+    }
+
+    /// Split rectangle into columns, left to right.
+    ///
+    /// Each fraction is the width of a column relative to the width of the whole rectangle.
+    /// The fractions should sum to at most `1.0`.
+    /// If they sum to less than that, the remainder is returned as a last column.
+    ///
+    /// An empty slice returns the original rectangle.
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 1.0));
+    ///
+    /// let columns = rect.split_horizontally(&[0.25, 0.5]);
+    /// assert_eq!(columns, vec![
+    ///     Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+    ///     Rect::from_min_max(pos2(1.0, 0.0), pos2(3.0, 1.0)),
+    ///     Rect::from_min_max(pos2(3.0, 0.0), pos2(4.0, 1.0)),
+    /// ]);
+    ///
+    /// assert_eq!(rect.split_horizontally(&[1.0]), vec![rect]);
+    /// assert_eq!(rect.split_horizontally(&[]), vec![rect]);
+    /// ```
+    #[inline]
+    pub fn split_horizontally(self, fractions: &[f32]) -> Vec<Self> {
+        let mut rects = Vec::with_capacity(fractions.len() + 1);
+        let mut rest = self;
+        for &fraction in fractions {
+            let (column, remainder) = rest.split_at_x(rest.min.x + fraction * self.width());
+            rects.push(column);
+            rest = remainder;
+        }
+        if rects.is_empty() || 0.0 < rest.width() {
+            rects.push(rest);
+        }
+        rects
+    }
+
+    /// Split rectangle into rows, top to bottom.
+    ///
+    /// Each fraction is the height of a row relative to the height of the whole rectangle.
+    /// The fractions should sum to at most `1.0`.
+    /// If they sum to less than that, the remainder is returned as a last row.
+    ///
+    /// An empty slice returns the original rectangle.
+    ///
+    /// ```
+    /// # use emath::{pos2, Rect};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 4.0));
+    ///
+    /// let rows = rect.split_vertically(&[0.5]);
+    /// assert_eq!(rows, vec![
+    ///     Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 2.0)),
+    ///     Rect::from_min_max(pos2(0.0, 2.0), pos2(1.0, 4.0)),
+    /// ]);
+    ///
+    /// assert_eq!(rect.split_vertically(&[1.0]), vec![rect]);
+    /// assert_eq!(rect.split_vertically(&[]), vec![rect]);
+    /// ```
+    #[inline]
+    pub fn split_vertically(self, fractions: &[f32]) -> Vec<Self> {
+        let mut rects = Vec::with_capacity(fractions.len() + 1);
+        let mut rest = self;
+        for &fraction in fractions {
+            let (row, remainder) = rest.split_at_y(rest.min.y + fraction * self.height());
+            rects.push(row);
+            rest = remainder;
+        }
+        if rects.is_empty() || 0.0 < rest.height() {
+            rects.push(rest);
+        }
+        rects
+    }
 }
 
 impl Rect {
@@ -830,6 +1068,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fit_and_fill() {
+        let container = Rect::from_min_max(pos2(10.0, 20.0), pos2(110.0, 70.0));
+        for size in [
+            vec2(1.0, 1.0),
+            vec2(300.0, 10.0),
+            vec2(3.0, 40.0),
+            vec2(100.0, 50.0),
+        ] {
+            let fitted = Rect::fit_in_rect(size, container);
+            assert!((fitted.aspect_ratio() - size.x / size.y).abs() < 1e-4);
+            assert!(container.expand(1e-4).contains_rect(fitted));
+            assert_eq!(fitted.center(), container.center());
+
+            let filled = Rect::fill_rect(size, container);
+            assert!((filled.aspect_ratio() - size.x / size.y).abs() < 1e-4);
+            assert!(filled.expand(1e-4).contains_rect(container));
+            assert_eq!(filled.center(), container.center());
+        }
+
+        let empty = Rect::fit_in_rect(Vec2::ZERO, container);
+        assert_eq!(empty, Rect::from_pos(container.center()));
+        let empty = Rect::fill_rect(vec2(0.0, 10.0), container);
+        assert_eq!(empty, Rect::from_pos(container.center()));
+    }
+
+    #[test]
+    fn test_snap_to_pixels() {
+        use crate::GuiRounding as _;
+
+        for pixels_per_point in [0.5, 1.0, 1.25, 1.5, 2.0, 3.0] {
+            for (min, max) in [
+                (pos2(0.0, 0.0), pos2(10.0, 10.0)),
+                (pos2(0.3, -1.7), pos2(11.1, 5.55)),
+                (pos2(-123.456, 42.42), pos2(-0.1, 1000.001)),
+            ] {
+                let rect = Rect::from_min_max(min, max);
+
+                let ceiled = rect.ceil_to_pixels(pixels_per_point);
+                assert!(ceiled.contains_rect(rect));
+                assert_eq!(ceiled.ceil_to_pixels(pixels_per_point), ceiled);
+
+                let floored = rect.floor_to_pixels(pixels_per_point);
+                assert!(rect.contains_rect(floored));
+                assert_eq!(floored.floor_to_pixels(pixels_per_point), floored);
+
+                let rounded = rect.round_to_pixels(pixels_per_point);
+                assert_eq!(rounded.round_to_pixels(pixels_per_point), rounded);
+            }
+        }
+    }
+
     #[test]
     fn test_ray_intersection() {
         let rect = Rect::from_min_max(pos2(1.0, 1.0), pos2(3.0, 3.0));
@@ -234,6 +234,16 @@ impl Vec2 {
         vec2(cos, sin)
     }
 
+    /// Rotate the vector by the given CW angle (in radians),
+    /// so that an angle of 𝞃/4 = 90° rotates the X axis to the Y axis.
+    ///
+    /// Shorthand for `Rot2::from_angle(angle) * self`.
+    #[must_use]
+    #[inline]
+    pub fn rotate(self, angle: f32) -> Self {
+        crate::Rot2::from_angle(angle) * self
+    }
+
     #[must_use]
     #[inline(always)]
     pub fn floor(self) -> Self {
@@ -565,4 +575,26 @@ mod test {
             assert!(vn.is_normalized());
         }
     }
+
+    #[test]
+    fn test_vec2_rotate() {
+        use crate::pos2;
+        use std::f32::consts::{PI, TAU};
+
+        let v = vec2(3.0, -4.0);
+        let rotated = v.rotate(TAU);
+        almost_eq!(rotated.x, v.x);
+        almost_eq!(rotated.y, v.y);
+
+        let rotated = Vec2::X.rotate(PI / 2.0);
+        almost_eq!(rotated.x, Vec2::Y.x);
+        almost_eq!(rotated.y, Vec2::Y.y);
+
+        let rotated = pos2(2.0, 1.0).rotate_around(pos2(1.0, 1.0), PI / 2.0);
+        almost_eq!(rotated.x, 1.0);
+        almost_eq!(rotated.y, 2.0);
+
+        let pivot = pos2(-5.0, 7.0);
+        assert_eq!(pivot.rotate_around(pivot, 1.234), pivot);
+    }
 }
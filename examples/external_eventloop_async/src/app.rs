@@ -43,11 +43,15 @@ pub fn run() -> io::Result<()> {
             };
 
             match winit_app.pump_eframe_app(&mut eventloop, None) {
-                EframePumpStatus::Continue(next) => control_flow = next,
+                EframePumpStatus::Continue(next, _next_repaint_time) => control_flow = next,
                 EframePumpStatus::Exit(code) => {
                     log::info!("exit code: {code}");
                     break;
                 }
+                EframePumpStatus::Err(err) => {
+                    log::error!("eframe error: {err}");
+                    break;
+                }
             }
 
             if let Some(mut guard) = guard.take() {